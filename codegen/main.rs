@@ -97,6 +97,8 @@ fn gen() -> Result<(), Box<dyn std::error::Error>> {
         f,
     )?;
 
+    gen_element_attributes("element_attributes.txt", f)?;
+
     writeln!(f, "{}", PHF_SRC)?;
 
     Ok(())
@@ -166,6 +168,113 @@ fn gen_map(
     Ok(())
 }
 
+fn read_names(path: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut spec = String::new();
+    fs::File::open(path)?.read_to_string(&mut spec)?;
+    Ok(spec.split('\n').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect())
+}
+
+// Generates `ElementId::allows_attribute`.
+//
+// Each group is generated as a separate function to keep the output small.
+fn gen_element_attributes(
+    spec_path: &str,
+    f: &mut fs::File,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let elements = read_names("elements.txt")?;
+    let attributes = read_names("attributes.txt")?;
+
+    let mut spec = String::new();
+    fs::File::open(spec_path)?.read_to_string(&mut spec)?;
+
+    let mut groups: Vec<(&str, Vec<&str>)> = Vec::new();
+    let mut rules: Vec<(&str, Vec<&str>)> = Vec::new();
+    for line in spec.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut iter = line.splitn(2, ':');
+        let name = iter.next().unwrap().trim();
+        let items: Vec<&str> = iter.next().ok_or("invalid line")?.split_whitespace().collect();
+
+        for item in &items {
+            if item.starts_with('@') {
+                if !groups.iter().any(|g| g.0 == &item[1..]) {
+                    return Err(format!("unknown group: {}", item).into());
+                }
+            } else if !attributes.iter().any(|a| a == item) {
+                return Err(format!("unknown attribute: {}", item).into());
+            }
+        }
+
+        if name.starts_with('@') {
+            groups.push((&name[1..], items));
+        } else {
+            if !elements.iter().any(|e| e == name) {
+                return Err(format!("unknown element: {}", name).into());
+            }
+
+            rules.push((name, items));
+        }
+    }
+
+    for element in &elements {
+        if !rules.iter().any(|r| r.0 == element) {
+            return Err(format!("missing element: {}", element).into());
+        }
+    }
+
+    writeln!(f, "impl ElementId {{")?;
+    writeln!(f, "    /// Checks that the attribute can be set on this element.")?;
+    writeln!(f, "    ///")?;
+    writeln!(f, "    /// Based on the SVG 1.1 specification.")?;
+    writeln!(f, "    /// CSS properties without a presentation attribute are allowed too.")?;
+    writeln!(f, "    pub fn allows_attribute(&self, aid: AttributeId) -> bool {{")?;
+    writeln!(f, "        match *self {{")?;
+    for &(name, ref items) in &rules {
+        writeln!(f, "            ElementId::{} => {},", to_enum_name(name), gen_check(items, 12))?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}\n")?;
+
+    for &(name, ref items) in &groups {
+        writeln!(f, "fn {}(aid: AttributeId) -> bool {{", group_fn_name(name))?;
+        writeln!(f, "    {}", gen_check(items, 4))?;
+        writeln!(f, "}}\n")?;
+    }
+
+    Ok(())
+}
+
+fn gen_check(items: &[&str], indent: usize) -> String {
+    let mut checks: Vec<String> = items.iter()
+        .filter(|s| s.starts_with('@'))
+        .map(|s| format!("{}(aid)", group_fn_name(&s[1..])))
+        .collect();
+
+    let attrs: Vec<String> = items.iter()
+        .filter(|s| !s.starts_with('@'))
+        .map(|s| format!("AttributeId::{}", to_enum_name(s)))
+        .collect();
+
+    if !attrs.is_empty() {
+        let pad = " ".repeat(indent);
+        checks.push(format!(
+            "match aid {{\n{}      {}\n{}    => true,\n{}    _ => false,\n{}}}",
+            pad, attrs.join(&format!("\n{}    | ", pad)), pad, pad, pad,
+        ));
+    }
+
+    checks.join(" || ")
+}
+
+fn group_fn_name(name: &str) -> String {
+    format!("is_{}_attribute", name.replace('-', "_"))
+}
+
 // some-string -> SomeString
 // some_string -> SomeString
 // some:string -> SomeString
@@ -77,6 +77,8 @@ impl Attribute {
     impl_is_type!(is_length_list);
     impl_is_type!(is_link);
     impl_is_type!(is_func_link);
+    impl_is_type!(is_external_link);
+    impl_is_type!(is_external_func_link);
     impl_is_type!(is_paint);
     impl_is_type!(is_number);
     impl_is_type!(is_number_list);
@@ -85,7 +87,9 @@ impl Attribute {
     impl_is_type!(is_string);
     impl_is_type!(is_transform);
     impl_is_type!(is_viewbox);
+    impl_is_type!(is_paint_order);
     impl_is_type!(is_link_container);
+    impl_is_type!(is_keyword);
 }
 
 impl fmt::Display for Attribute {
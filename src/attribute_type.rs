@@ -8,12 +8,23 @@ use crate::{
 pub trait AttributeType {
     /// Returns `true` if the current attribute is part of
     /// [presentation attributes](https://www.w3.org/TR/SVG/propidx.html).
+    ///
+    /// Also includes SVG 2 `paint-order`, `solid-color`, `solid-opacity` and `vector-effect`.
     fn is_presentation(&self) -> bool;
 
     /// Returns `true` if the current attribute is part of inheritable
     /// [presentation attributes](https://www.w3.org/TR/SVG/propidx.html).
     fn is_inheritable(&self) -> bool;
 
+    /// Returns `true` if the current attribute is a CSS property
+    /// that doesn't have a presentation attribute.
+    ///
+    /// List: `isolation`, `mix-blend-mode`.
+    ///
+    /// Such properties can be set only via CSS, therefore they are written
+    /// into the `style` attribute.
+    fn is_style_only(&self) -> bool;
+
     /// Returns `true` if the current attribute is part of
     /// [animation event attributes](https://www.w3.org/TR/SVG/intro.html#TermAnimationEventAttribute).
     fn is_animation_event(&self) -> bool;
@@ -70,6 +81,7 @@ macro_rules! is_func {
 impl AttributeType for Attribute {
     is_func!(is_presentation);
     is_func!(is_inheritable);
+    is_func!(is_style_only);
     is_func!(is_animation_event);
     is_func!(is_graphical_event);
     is_func!(is_document_event);
@@ -124,8 +136,11 @@ impl AttributeType for AttributeId {
             | AttributeId::Mask
             | AttributeId::Opacity
             | AttributeId::Overflow
+            | AttributeId::PaintOrder
             | AttributeId::PointerEvents
             | AttributeId::ShapeRendering
+            | AttributeId::SolidColor
+            | AttributeId::SolidOpacity
             | AttributeId::StopColor
             | AttributeId::StopOpacity
             | AttributeId::Stroke
@@ -140,6 +155,7 @@ impl AttributeType for AttributeId {
             | AttributeId::TextDecoration
             | AttributeId::TextRendering
             | AttributeId::UnicodeBidi
+            | AttributeId::VectorEffect
             | AttributeId::Visibility
             | AttributeId::WordSpacing
             | AttributeId::WritingMode => true,
@@ -155,6 +171,14 @@ impl AttributeType for AttributeId {
         }
     }
 
+    fn is_style_only(&self) -> bool {
+        match *self {
+              AttributeId::Isolation
+            | AttributeId::MixBlendMode => true,
+            _ => false,
+        }
+    }
+
     fn is_animation_event(&self) -> bool {
         match *self {
               AttributeId::Onbegin
@@ -259,10 +283,13 @@ fn is_non_inheritable(id: AttributeId) -> bool {
         | AttributeId::Mask
         | AttributeId::Opacity
         | AttributeId::Overflow
+        | AttributeId::SolidColor
+        | AttributeId::SolidOpacity
         | AttributeId::StopColor
         | AttributeId::StopOpacity
         | AttributeId::TextDecoration
         | AttributeId::UnicodeBidi
+        | AttributeId::VectorEffect
         | AttributeId::Visibility => true,
         _ => false
     }
@@ -4,21 +4,46 @@ use crate::{
     Angle,
     AspectRatio,
     AttributeId,
+    BlendMode,
     Color,
+    DisplayMode,
+    FillRule,
+    FontStretch,
+    FontStyle,
+    FontVariant,
+    FontWeight,
+    Isolation,
     Length,
     LengthList,
     LengthUnit,
+    LineCap,
+    LineJoin,
     Node,
     NumberList,
     PaintFallback,
+    PaintOrder,
     Path,
     Points,
+    TextAnchor,
     Transform,
     ValueWriteOptions,
+    VectorEffect,
     ViewBox,
+    Visibility,
     WriteBuffer,
 };
 
+/// A reference to an element of an external document.
+///
+/// Like `sprite.svg#icon` or `url(sprite.svg#grad)`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExternalIri {
+    /// A path or URL of the external document.
+    pub path: String,
+    /// An ID of the referenced element.
+    pub id: String,
+}
+
 // TODO: custom debug
 
 /// Value of the SVG attribute.
@@ -29,21 +54,39 @@ pub enum AttributeValue {
     Inherit,
     CurrentColor,
     AspectRatio(AspectRatio),
+    BlendMode(BlendMode),
     Color(Color),
+    DisplayMode(DisplayMode),
+    FillRule(FillRule),
+    FontStretch(FontStretch),
+    FontStyle(FontStyle),
+    FontVariant(FontVariant),
+    FontWeight(FontWeight),
+    /// External IRI
+    ExternalLink(ExternalIri),
+    /// External FuncIRI with an optional paint fallback
+    ExternalFuncLink(ExternalIri, Option<PaintFallback>),
     /// FuncIRI
     FuncLink(Node),
     Paint(Node, Option<PaintFallback>),
+    PaintOrder(PaintOrder),
+    Isolation(Isolation),
     Length(Length),
     LengthList(LengthList),
     Angle(Angle),
+    LineCap(LineCap),
+    LineJoin(LineJoin),
     /// IRI
     Link(Node),
     Number(f64),
     NumberList(NumberList),
     Path(Path),
     Points(Points),
+    TextAnchor(TextAnchor),
     Transform(Transform),
+    VectorEffect(VectorEffect),
     ViewBox(ViewBox),
+    Visibility(Visibility),
     String(String),
 }
 
@@ -69,6 +112,20 @@ impl_from!(Points, Points);
 impl_from!(String, String);
 impl_from!(Transform, Transform);
 impl_from!(ViewBox, ViewBox);
+impl_from!(BlendMode, BlendMode);
+impl_from!(DisplayMode, DisplayMode);
+impl_from!(FillRule, FillRule);
+impl_from!(FontStretch, FontStretch);
+impl_from!(FontStyle, FontStyle);
+impl_from!(FontVariant, FontVariant);
+impl_from!(FontWeight, FontWeight);
+impl_from!(Isolation, Isolation);
+impl_from!(LineCap, LineCap);
+impl_from!(LineJoin, LineJoin);
+impl_from!(PaintOrder, PaintOrder);
+impl_from!(TextAnchor, TextAnchor);
+impl_from!(VectorEffect, VectorEffect);
+impl_from!(Visibility, Visibility);
 
 // TODO: bad, hidden allocation
 impl<'a> From<&'a str> for AttributeValue {
@@ -141,6 +198,8 @@ impl AttributeValue {
     impl_is_type!(is_angle, Angle);
     impl_is_type!(is_link, Link);
     impl_is_type!(is_func_link, FuncLink);
+    impl_is_type!(is_external_link, ExternalLink);
+    impl_is_type!(is_external_func_link, ExternalFuncLink);
     impl_is_type!(is_paint, Paint);
     impl_is_type!(is_number, Number);
     impl_is_type!(is_number_list, NumberList);
@@ -149,6 +208,29 @@ impl AttributeValue {
     impl_is_type!(is_string, String);
     impl_is_type!(is_transform, Transform);
     impl_is_type!(is_viewbox, ViewBox);
+    impl_is_type!(is_paint_order, PaintOrder);
+
+    /// Checks that the current attribute value contains a typed keyword.
+    ///
+    /// E.g. `LineCap`, `FillRule`, `Visibility`, etc.
+    pub fn is_keyword(&self) -> bool {
+        match *self {
+              AttributeValue::BlendMode(_)
+            | AttributeValue::DisplayMode(_)
+            | AttributeValue::FillRule(_)
+            | AttributeValue::FontStretch(_)
+            | AttributeValue::FontStyle(_)
+            | AttributeValue::FontVariant(_)
+            | AttributeValue::FontWeight(_)
+            | AttributeValue::Isolation(_)
+            | AttributeValue::LineCap(_)
+            | AttributeValue::LineJoin(_)
+            | AttributeValue::TextAnchor(_)
+            | AttributeValue::VectorEffect(_)
+            | AttributeValue::Visibility(_) => true,
+            _ => false,
+        }
+    }
 
     /// Checks that the current attribute value contains a `Node`.
     ///
@@ -193,43 +275,49 @@ impl AttributeValue {
             | AttributeId::StrokeDasharray
             | AttributeId::TextDecoration => Some(AttributeValue::None),
 
-              AttributeId::FontStretch
-            | AttributeId::FontStyle
-            | AttributeId::FontVariant
-            | AttributeId::FontWeight
-            | AttributeId::LetterSpacing
+              AttributeId::LetterSpacing
             | AttributeId::UnicodeBidi
             | AttributeId::WordSpacing => some!("normal"),
 
               AttributeId::Fill
             | AttributeId::FloodColor
+            | AttributeId::SolidColor
             | AttributeId::StopColor => some!(Color::black()),
 
               AttributeId::FillOpacity
             | AttributeId::FloodOpacity
             | AttributeId::Opacity
+            | AttributeId::SolidOpacity
             | AttributeId::StopOpacity
             | AttributeId::StrokeOpacity => some!(1.0),
 
               AttributeId::ClipRule
-            | AttributeId::FillRule => some!("nonzero"),
+            | AttributeId::FillRule => some!(FillRule::NonZero),
 
             AttributeId::BaselineShift =>               some!("baseline"),
             AttributeId::ColorInterpolation =>          some!("sRGB"),
             AttributeId::ColorInterpolationFilters =>   some!("linearRGB"),
             AttributeId::Direction =>                   some!("ltr"),
-            AttributeId::Display =>                     some!("inline"),
+            AttributeId::Display =>                     some!(DisplayMode::Inline),
             AttributeId::EnableBackground =>            some!("accumulate"),
             AttributeId::FontSize =>                    some!("medium"),
+            AttributeId::FontStretch =>                 some!(FontStretch::Normal),
+            AttributeId::FontStyle =>                   some!(FontStyle::Normal),
+            AttributeId::FontVariant =>                 some!(FontVariant::Normal),
+            AttributeId::FontWeight =>                  some!(FontWeight::Normal),
             AttributeId::GlyphOrientationHorizontal =>  some!("0deg"),
+            AttributeId::Isolation =>                   some!(Isolation::Auto),
             AttributeId::LightingColor =>               some!(Color::white()),
+            AttributeId::MixBlendMode =>                some!(BlendMode::Normal),
+            AttributeId::PaintOrder =>                  some!(PaintOrder::default()),
             AttributeId::StrokeDashoffset =>            some!((0.0, LengthUnit::None)),
-            AttributeId::StrokeLinecap =>               some!("butt"),
-            AttributeId::StrokeLinejoin =>              some!("miter"),
+            AttributeId::StrokeLinecap =>               some!(LineCap::Butt),
+            AttributeId::StrokeLinejoin =>              some!(LineJoin::Miter),
             AttributeId::StrokeMiterlimit =>            some!((4.0, LengthUnit::None)),
             AttributeId::StrokeWidth =>                 some!((1.0, LengthUnit::None)),
-            AttributeId::TextAnchor =>                  some!("start"),
-            AttributeId::Visibility =>                  some!("visible"),
+            AttributeId::TextAnchor =>                  some!(TextAnchor::Start),
+            AttributeId::VectorEffect =>                some!(VectorEffect::None),
+            AttributeId::Visibility =>                  some!(Visibility::Visible),
             AttributeId::WritingMode =>                 some!("lr-tb"),
             _ => None,
         }
@@ -288,15 +376,16 @@ impl WriteBuffer for AttributeValue {
                 buf.extend_from_slice(b"url(#");
                 buf.extend_from_slice(n.id().as_bytes());
                 buf.push(b')');
-
-                if let Some(fallback) = *fallback {
-                    buf.push(b' ');
-                    match fallback {
-                        PaintFallback::None => buf.extend_from_slice(b"none"),
-                        PaintFallback::CurrentColor => buf.extend_from_slice(b"currentColor"),
-                        PaintFallback::Color(ref c) => c.write_buf_opt(opt, buf),
-                    }
-                }
+                write_fallback(fallback, opt, buf);
+            }
+            AttributeValue::ExternalLink(ref iri) => {
+                write_external_iri(iri, buf);
+            }
+            AttributeValue::ExternalFuncLink(ref iri, ref fallback) => {
+                buf.extend_from_slice(b"url(");
+                write_external_iri(iri, buf);
+                buf.push(b')');
+                write_fallback(fallback, opt, buf);
             }
             AttributeValue::Color(ref c) => {
                 c.write_buf_opt(opt, buf);
@@ -307,6 +396,65 @@ impl WriteBuffer for AttributeValue {
             AttributeValue::AspectRatio(ratio) => {
                 ratio.write_buf_opt(opt, buf);
             }
+            AttributeValue::BlendMode(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::DisplayMode(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::FillRule(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::FontStretch(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::FontStyle(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::FontVariant(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::FontWeight(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::Isolation(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::LineCap(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::LineJoin(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::PaintOrder(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::TextAnchor(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::VectorEffect(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+            AttributeValue::Visibility(v) => {
+                v.write_buf_opt(opt, buf);
+            }
+        }
+    }
+}
+
+fn write_external_iri(iri: &ExternalIri, buf: &mut Vec<u8>) {
+    buf.extend_from_slice(iri.path.as_bytes());
+    buf.push(b'#');
+    buf.extend_from_slice(iri.id.as_bytes());
+}
+
+fn write_fallback(fallback: &Option<PaintFallback>, opt: &ValueWriteOptions, buf: &mut Vec<u8>) {
+    if let Some(fallback) = *fallback {
+        buf.push(b' ');
+        match fallback {
+            PaintFallback::None => buf.extend_from_slice(b"none"),
+            PaintFallback::CurrentColor => buf.extend_from_slice(b"currentColor"),
+            PaintFallback::Color(ref c) => c.write_buf_opt(opt, buf),
         }
     }
 }
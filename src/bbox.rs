@@ -0,0 +1,437 @@
+// Bounding box calculation.
+
+use std::f64;
+
+use crate::{
+    AttributeId as AId,
+    AttributeValue,
+    DisplayMode,
+    ElementId,
+    Node,
+    Path,
+    PathSegment,
+    TextAnchor,
+    Transform,
+};
+use crate::geom::{
+    BBox,
+    CenterArc,
+    Rect,
+};
+use crate::shapes::shape_to_path;
+use crate::units::{
+    resolve_font_size,
+    resolve_length,
+};
+
+// Text metrics approximation, relative to the font size.
+const TEXT_ASCENT: f64 = 0.8;
+const TEXT_DESCENT: f64 = 0.2;
+const TEXT_ADVANCE: f64 = 0.5;
+
+/// Calculates a node bounding box in the canvas coordinates.
+pub fn calc_node_bbox(node: &Node, with_stroke: bool) -> Option<Rect> {
+    let ts = parent_transform(node);
+    calc(node, &ts, with_stroke, &mut Vec::new())
+}
+
+/// Returns an accumulated transform of all node ancestors.
+///
+/// The node's own transform is not included.
+pub fn parent_transform(node: &Node) -> Transform {
+    let mut list: Vec<Transform> = node.ancestors().skip(1).filter_map(|n| {
+        match n.attributes().get_value(AId::Transform) {
+            Some(&AttributeValue::Transform(ts)) => Some(ts),
+            _ => None,
+        }
+    }).collect();
+    list.reverse();
+
+    let mut ts = Transform::default();
+    for t in &list {
+        ts.append(t);
+    }
+
+    ts
+}
+
+fn calc(node: &Node, parent_ts: &Transform, with_stroke: bool, uses: &mut Vec<Node>) -> Option<Rect> {
+    let eid = node.tag_id()?;
+
+    if let Some(&AttributeValue::DisplayMode(DisplayMode::None))
+        = node.attributes().get_value(AId::Display)
+    {
+        return None;
+    }
+
+    let mut ts = *parent_ts;
+    if let Some(&AttributeValue::Transform(t)) = node.attributes().get_value(AId::Transform) {
+        ts.append(&t);
+    }
+
+    let bbox = match eid {
+          ElementId::Rect
+        | ElementId::Circle
+        | ElementId::Ellipse
+        | ElementId::Line
+        | ElementId::Polyline
+        | ElementId::Polygon => {
+            path_bbox(&shape_to_path(node)?, &ts)?
+        }
+        ElementId::Path => {
+            match node.attributes().get_value(AId::D) {
+                Some(AttributeValue::Path(path)) => path_bbox(path, &ts)?,
+                _ => return None,
+            }
+        }
+          ElementId::G
+        | ElementId::A
+        | ElementId::Switch
+        | ElementId::Svg => {
+            return children_bbox(node, &ts, with_stroke, uses);
+        }
+        ElementId::Use => {
+            return use_bbox(node, &ts, with_stroke, uses);
+        }
+          ElementId::Text
+        | ElementId::Tspan
+        | ElementId::Tref
+        | ElementId::TextPath => {
+            text_bbox(node)?.transform(&ts)
+        }
+          ElementId::Image
+        | ElementId::ForeignObject => {
+            let w = resolve_length(node, AId::Width, 0.0);
+            let h = resolve_length(node, AId::Height, 0.0);
+            if !(w > 0.0 && h > 0.0) {
+                return None;
+            }
+
+            let x = resolve_length(node, AId::X, 0.0);
+            let y = resolve_length(node, AId::Y, 0.0);
+            Rect::new(x, y, w, h).transform(&ts)
+        }
+        _ => return None,
+    };
+
+    if with_stroke {
+        if let Some(width) = resolve_stroke_width(node) {
+            // Exact only for round joins and caps.
+            let (sx, sy) = ts.get_scale();
+            let hw = width / 2.0;
+            return Some(Rect::from_ltrb(
+                bbox.left() - hw * sx,
+                bbox.top() - hw * sy,
+                bbox.right() + hw * sx,
+                bbox.bottom() + hw * sy,
+            ));
+        }
+    }
+
+    Some(bbox)
+}
+
+fn children_bbox(
+    node: &Node,
+    ts: &Transform,
+    with_stroke: bool,
+    uses: &mut Vec<Node>,
+) -> Option<Rect> {
+    let mut bbox: Option<Rect> = None;
+    for child in node.children() {
+        if let Some(r) = calc(&child, ts, with_stroke, uses) {
+            bbox = Some(match bbox {
+                Some(bbox) => bbox.union(&r),
+                None => r,
+            });
+        }
+    }
+
+    bbox
+}
+
+fn use_bbox(node: &Node, ts: &Transform, with_stroke: bool, uses: &mut Vec<Node>) -> Option<Rect> {
+    // Prevent an endless recursion.
+    if uses.contains(node) {
+        return None;
+    }
+
+    let link = match node.attributes().get_value(AId::Href) {
+        Some(AttributeValue::Link(link)) => link.clone(),
+        _ => return None,
+    };
+
+    let mut ts = *ts;
+    ts.append(&Transform::new_translate(
+        resolve_length(node, AId::X, 0.0),
+        resolve_length(node, AId::Y, 0.0),
+    ));
+
+    uses.push(node.clone());
+    let bbox = if link.has_tag_name(ElementId::Symbol) {
+        children_bbox(&link, &ts, with_stroke, uses)
+    } else {
+        calc(&link, &ts, with_stroke, uses)
+    };
+    uses.pop();
+
+    bbox
+}
+
+// An approximate text bounding box in the user coordinates.
+//
+// Real glyph metrics are unknown, so we assume that each character has
+// the same advance and the whole text is a single line.
+fn text_bbox(node: &Node) -> Option<Rect> {
+    let count: usize = node.descendants()
+                           .filter(|n| n.is_text())
+                           .map(|n| n.text().chars().count())
+                           .sum();
+    if count == 0 {
+        return None;
+    }
+
+    let pos = |aid| {
+        node.ancestors()
+            .take_while(|n| n.is_element())
+            .find(|n| n.has_attribute(aid))
+            .map(|n| resolve_length(&n, aid, 0.0))
+            .unwrap_or(0.0)
+    };
+
+    let font_size = resolve_font_size(node);
+    let width = count as f64 * font_size * TEXT_ADVANCE;
+
+    let mut x = pos(AId::X);
+    match find_inherited(node, AId::TextAnchor) {
+        Some(AttributeValue::TextAnchor(TextAnchor::Middle)) => x -= width / 2.0,
+        Some(AttributeValue::TextAnchor(TextAnchor::End)) => x -= width,
+        _ => {}
+    }
+
+    let y = pos(AId::Y) - font_size * TEXT_ASCENT;
+    Some(Rect::new(x, y, width, font_size * (TEXT_ASCENT + TEXT_DESCENT)))
+}
+
+// Returns `None` when the node doesn't have a stroke.
+pub fn resolve_stroke_width(node: &Node) -> Option<f64> {
+    match find_inherited(node, AId::Stroke) {
+        None | Some(AttributeValue::None) => return None,
+        _ => {}
+    }
+
+    let width = node.ancestors()
+        .find(|n| {
+            match n.attributes().get_value(AId::StrokeWidth) {
+                Some(&AttributeValue::Length(_)) => true,
+                _ => false,
+            }
+        })
+        .map(|n| resolve_length(&n, AId::StrokeWidth, 1.0))
+        .unwrap_or(1.0);
+
+    if width > 0.0 { Some(width) } else { None }
+}
+
+/// Returns the first non-`inherit` value of the attribute in the node or its ancestors.
+pub fn find_inherited(node: &Node, aid: AId) -> Option<AttributeValue> {
+    for n in node.ancestors() {
+        match n.attributes().get_value(aid) {
+            Some(&AttributeValue::Inherit) | None => {}
+            Some(v) => return Some(v.clone()),
+        }
+    }
+
+    None
+}
+
+/// Calculates an exact path bounding box after the transformation.
+pub fn path_bbox(path: &Path, ts: &Transform) -> Option<Rect> {
+    let mut path = path.clone();
+    path.conv_to_absolute();
+
+    let mut bbox = BBox::new();
+    let (mut px, mut py) = (0.0, 0.0);
+    let (mut mx, mut my) = (0.0, 0.0);
+    // The last control point of the previous curve, used by smooth curves.
+    let mut prev_cubic: Option<(f64, f64)> = None;
+    let mut prev_quad: Option<(f64, f64)> = None;
+
+    for seg in path.iter() {
+        let mut cubic = None;
+        let mut quad = None;
+
+        match *seg {
+            PathSegment::MoveTo { x, y, .. } => {
+                bbox.add_point(ts.apply(x, y));
+                mx = x;
+                my = y;
+            }
+            PathSegment::LineTo { x, y, .. } => {
+                bbox.add_point(ts.apply(x, y));
+            }
+            PathSegment::HorizontalLineTo { x, .. } => {
+                bbox.add_point(ts.apply(x, py));
+            }
+            PathSegment::VerticalLineTo { y, .. } => {
+                bbox.add_point(ts.apply(px, y));
+            }
+            PathSegment::CurveTo { x1, y1, x2, y2, x, y, .. } => {
+                add_cubic(&mut bbox, ts, (px, py), (x1, y1), (x2, y2), (x, y));
+                cubic = Some((x2, y2));
+            }
+            PathSegment::SmoothCurveTo { x2, y2, x, y, .. } => {
+                let (x1, y1) = reflect(prev_cubic, px, py);
+                add_cubic(&mut bbox, ts, (px, py), (x1, y1), (x2, y2), (x, y));
+                cubic = Some((x2, y2));
+            }
+            PathSegment::Quadratic { x1, y1, x, y, .. } => {
+                add_quad(&mut bbox, ts, (px, py), (x1, y1), (x, y));
+                quad = Some((x1, y1));
+            }
+            PathSegment::SmoothQuadratic { x, y, .. } => {
+                let (x1, y1) = reflect(prev_quad, px, py);
+                add_quad(&mut bbox, ts, (px, py), (x1, y1), (x, y));
+                quad = Some((x1, y1));
+            }
+            PathSegment::EllipticalArc { .. } => {
+                add_arc(&mut bbox, ts, (px, py), seg);
+            }
+            PathSegment::ClosePath { .. } => {
+                px = mx;
+                py = my;
+            }
+        }
+
+        if let (Some(x), Some(y)) = (seg.x(), seg.y()) {
+            px = x;
+            py = y;
+        } else if let Some(x) = seg.x() {
+            px = x;
+        } else if let Some(y) = seg.y() {
+            py = y;
+        }
+
+        prev_cubic = cubic;
+        prev_quad = quad;
+    }
+
+    bbox.to_rect()
+}
+
+fn reflect(p: Option<(f64, f64)>, px: f64, py: f64) -> (f64, f64) {
+    match p {
+        Some((x, y)) => (px * 2.0 - x, py * 2.0 - y),
+        None => (px, py),
+    }
+}
+
+fn add_quad(bbox: &mut BBox, ts: &Transform, p0: (f64, f64), p1: (f64, f64), p2: (f64, f64)) {
+    // A quadratic curve can be represented by a cubic one exactly.
+    let c1 = (p0.0 + 2.0 / 3.0 * (p1.0 - p0.0), p0.1 + 2.0 / 3.0 * (p1.1 - p0.1));
+    let c2 = (p2.0 + 2.0 / 3.0 * (p1.0 - p2.0), p2.1 + 2.0 / 3.0 * (p1.1 - p2.1));
+    add_cubic(bbox, ts, p0, c1, c2, p2);
+}
+
+fn add_cubic(
+    bbox: &mut BBox,
+    ts: &Transform,
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    p3: (f64, f64),
+) {
+    // Affine transformation of a Bezier curve is a transformation of its control points.
+    let p0 = ts.apply(p0.0, p0.1);
+    let p1 = ts.apply(p1.0, p1.1);
+    let p2 = ts.apply(p2.0, p2.1);
+    let p3 = ts.apply(p3.0, p3.1);
+
+    bbox.add_point(p0);
+    bbox.add_point(p3);
+
+    for t in cubic_extrema(p0.0, p1.0, p2.0, p3.0).iter().filter_map(|t| *t) {
+        bbox.add_x(cubic_at(p0.0, p1.0, p2.0, p3.0, t));
+    }
+
+    for t in cubic_extrema(p0.1, p1.1, p2.1, p3.1).iter().filter_map(|t| *t) {
+        bbox.add_y(cubic_at(p0.1, p1.1, p2.1, p3.1, t));
+    }
+}
+
+fn cubic_at(p0: f64, p1: f64, p2: f64, p3: f64, t: f64) -> f64 {
+    let mt = 1.0 - t;
+    mt * mt * mt * p0 + 3.0 * mt * mt * t * p1 + 3.0 * mt * t * t * p2 + t * t * t * p3
+}
+
+// Returns curve parameters in the (0, 1) range, where the derivative is zero.
+fn cubic_extrema(p0: f64, p1: f64, p2: f64, p3: f64) -> [Option<f64>; 2] {
+    let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+    let b = 2.0 * (p0 - 2.0 * p1 + p2);
+    let c = p1 - p0;
+
+    let valid = |t: f64| if t > 0.0 && t < 1.0 { Some(t) } else { None };
+
+    if a.abs() < 1e-12 {
+        if b.abs() < 1e-12 {
+            return [None, None];
+        }
+
+        return [valid(-c / b), None];
+    }
+
+    let d = b * b - 4.0 * a * c;
+    if d < 0.0 {
+        return [None, None];
+    }
+
+    let sd = d.sqrt();
+    [valid((-b + sd) / (2.0 * a)), valid((-b - sd) / (2.0 * a))]
+}
+
+fn add_arc(bbox: &mut BBox, ts: &Transform, p1: (f64, f64), seg: &PathSegment) {
+    let arc = match *seg {
+        PathSegment::EllipticalArc { rx, ry, x_axis_rotation, large_arc, sweep, x, y, .. } => {
+            bbox.add_point(ts.apply(p1.0, p1.1));
+            bbox.add_point(ts.apply(x, y));
+            CenterArc::new(p1, rx, ry, x_axis_rotation, large_arc, sweep, (x, y))
+        }
+        _ => return,
+    };
+
+    // Treated as a straight line.
+    let arc = match arc {
+        Some(arc) => arc,
+        None => return,
+    };
+
+    let CenterArc { cx, cy, rx, ry, sin_phi, cos_phi, theta1, dtheta } = arc;
+
+    let in_sweep = |t: f64| {
+        let full = 2.0 * f64::consts::PI;
+        let d = if dtheta >= 0.0 { t - theta1 } else { theta1 - t };
+        let d = ((d % full) + full) % full;
+        d <= dtheta.abs()
+    };
+
+    // A transformed arc point is `c + A*cos(t) + B*sin(t)` on each axis.
+    let (tcx, tcy) = ts.apply(cx, cy);
+    let ax = rx * (ts.a * cos_phi + ts.c * sin_phi);
+    let bx = ry * (-ts.a * sin_phi + ts.c * cos_phi);
+    let ay = rx * (ts.b * cos_phi + ts.d * sin_phi);
+    let by = ry * (-ts.b * sin_phi + ts.d * cos_phi);
+
+    let t = bx.atan2(ax);
+    for &t in &[t, t + f64::consts::PI] {
+        if in_sweep(t) {
+            bbox.add_x(tcx + ax * t.cos() + bx * t.sin());
+        }
+    }
+
+    let t = by.atan2(ay);
+    for &t in &[t, t + f64::consts::PI] {
+        if in_sweep(t) {
+            bbox.add_y(tcy + ay * t.cos() + by * t.sin());
+        }
+    }
+}
@@ -0,0 +1,366 @@
+// Removing of unused and duplicated elements, ids and redundant attributes.
+
+use log::warn;
+
+use crate::{
+    Attribute,
+    AttributeId as AId,
+    AttributeType,
+    AttributeValue,
+    Document,
+    ElementId,
+    ElementType,
+    LengthUnit,
+    Node,
+    NodeType,
+};
+
+/// Removes referenced elements that are not used by any other element.
+pub fn remove_unused_defs(doc: &mut Document) -> usize {
+    let mut count = 0;
+
+    // Removing an element may make elements referenced by it unused,
+    // so we have to repeat until nothing is removed.
+    loop {
+        let nodes: Vec<Node> = doc.root().descendants()
+            .filter(is_removable_def)
+            .collect();
+
+        if nodes.is_empty() {
+            break;
+        }
+
+        for node in nodes {
+            // The node may be already removed along with its parent.
+            if node.parent().is_some() {
+                doc.remove_node(node);
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn is_removable_def(node: &Node) -> bool {
+    let is_def = node.is_referenced() || {
+        // Graphic elements inside `defs` can be rendered only via `use`.
+        let in_defs = node.parent().map(|p| p.has_tag_name(ElementId::Defs)) == Some(true);
+        in_defs && (node.is_graphic() || node.is_container())
+    };
+
+    if !is_def {
+        return false;
+    }
+
+    // Links from the element's own subtree, like self-referencing patterns,
+    // don't make it used.
+    !node.descendants().any(|n| {
+        n.linked_nodes().iter().any(|l| !l.ancestors().any(|a| a == *node))
+    })
+}
+
+/// Replaces links to referenced elements that are equal to the previous ones
+/// and removes them.
+pub fn remove_duplicated_defs(doc: &mut Document) -> usize {
+    let mut count = 0;
+
+    // Elements that were linking to different duplicates are equal now,
+    // so we have to repeat until nothing is removed.
+    loop {
+        let nodes: Vec<Node> = doc.root().descendants()
+            .filter(|n| n.is_referenced())
+            .collect();
+
+        let mut originals: Vec<Node> = Vec::new();
+        let mut is_changed = false;
+        for node in nodes {
+            // The node may be already removed along with its parent.
+            if node.parent().is_none() {
+                continue;
+            }
+
+            let orig = originals.iter()
+                .find(|n| {
+                    n.parent().is_some()
+                    && is_same_element(n, &node)
+                    && is_same_inherited_attributes(n, &node)
+                })
+                .cloned();
+
+            let orig = match orig {
+                Some(orig) => orig,
+                None => {
+                    originals.push(node);
+                    continue;
+                }
+            };
+
+            // Children that are used outside the element must be preserved.
+            let has_used_children = node.descendants().skip(1).any(|n| {
+                n.linked_nodes().iter().any(|l| !l.ancestors().any(|a| a == node))
+            });
+
+            if has_used_children {
+                continue;
+            }
+
+            if retarget_links(&node, &orig) {
+                doc.remove_node(node);
+                count += 1;
+                is_changed = true;
+            }
+        }
+
+        if !is_changed {
+            break;
+        }
+    }
+
+    count
+}
+
+// Moves all links from `node` to `new_node`.
+//
+// Either all links are moved or none of them.
+fn retarget_links(node: &Node, new_node: &Node) -> bool {
+    // Linked nodes with their original attributes.
+    let mut moved: Vec<(Node, Attribute)> = Vec::new();
+
+    let linked_nodes = node.linked_nodes().clone();
+    for mut linked in linked_nodes {
+        let attrs: Vec<(Attribute, Attribute)> = linked.attributes().iter()
+            .filter_map(|attr| {
+                let value = match attr.value {
+                    AttributeValue::Link(ref link) if *link == *node => {
+                        AttributeValue::Link(new_node.clone())
+                    }
+                    AttributeValue::FuncLink(ref link) if *link == *node => {
+                        AttributeValue::FuncLink(new_node.clone())
+                    }
+                    AttributeValue::Paint(ref link, fallback) if *link == *node => {
+                        AttributeValue::Paint(new_node.clone(), fallback)
+                    }
+                    _ => return None,
+                };
+
+                Some((attr.clone(), Attribute { name: attr.name.clone(), value }))
+            })
+            .collect();
+
+        for (old_attr, attr) in attrs {
+            if let Err(e) = linked.set_attribute_checked(attr) {
+                warn!("Failed to replace a link to a duplicated element: {}.", e);
+
+                // Restore in the reverse order, so each step returns
+                // to an already valid state and cannot fail.
+                for (mut n, attr) in moved.into_iter().rev() {
+                    n.set_attribute(attr);
+                }
+
+                return false;
+            }
+
+            moved.push((linked.clone(), old_attr));
+        }
+    }
+
+    true
+}
+
+// Compares nodes with their children, ignoring ids.
+fn is_same_element(node1: &Node, node2: &Node) -> bool {
+    if node1.node_type() != node2.node_type() {
+        return false;
+    }
+
+    if node1.node_type() == NodeType::Element {
+        if *node1.tag_name() != *node2.tag_name() {
+            return false;
+        }
+
+        let attrs1 = node1.attributes();
+        let attrs2 = node2.attributes();
+        if attrs1.len() != attrs2.len() {
+            return false;
+        }
+
+        // The attributes order is not important.
+        let is_same_attrs = attrs1.iter().all(|attr| {
+            attrs2.get(attr.name.as_ref()).map(|a| a.value == attr.value) == Some(true)
+        });
+
+        if !is_same_attrs {
+            return false;
+        }
+    } else if *node1.text() != *node2.text() {
+        return false;
+    }
+
+    let mut children1 = node1.children();
+    let mut children2 = node2.children();
+    loop {
+        match (children1.next(), children2.next()) {
+            (Some(c1), Some(c2)) => {
+                if !is_same_element(&c1, &c2) {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+// Compares attributes inherited by nodes from their ancestors.
+//
+// Equal elements under different ancestors can still be rendered differently.
+fn is_same_inherited_attributes(node1: &Node, node2: &Node) -> bool {
+    let attrs1 = inherited_attributes(node1);
+    let attrs2 = inherited_attributes(node2);
+    if attrs1.len() != attrs2.len() {
+        return false;
+    }
+
+    attrs1.iter().all(|attr| {
+        attrs2.iter().any(|a| a.name == attr.name && a.value == attr.value)
+    })
+}
+
+// Collects inheritable attributes of the node ancestors.
+//
+// The closest ancestor attribute wins.
+fn inherited_attributes(node: &Node) -> Vec<Attribute> {
+    let mut attrs: Vec<Attribute> = Vec::new();
+    for parent in node.ancestors().skip(1).filter(|n| n.is_element()) {
+        for attr in parent.attributes().iter() {
+            let is_inherited = match attr.id() {
+                Some(aid) => is_inherited(aid),
+                None => false,
+            };
+
+            if is_inherited && !attrs.iter().any(|a| a.name == attr.name) {
+                attrs.push(attr.clone());
+            }
+        }
+    }
+
+    attrs
+}
+
+/// Removes ids that are not used by any links.
+pub fn remove_unused_ids(root: &Node) -> usize {
+    let mut count = 0;
+    for mut node in root.descendants() {
+        if node.is_element() && node.has_id() && !node.is_used() {
+            node.set_id(String::new());
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Removes attributes that are equal to their default values.
+pub fn remove_default_attributes(root: &Node) -> usize {
+    remove_attributes(root, |node, aid, value| {
+        let def = match AttributeValue::default_value(aid) {
+            Some(def) => def,
+            None => return false,
+        };
+
+        if !is_same_value(value, &def) {
+            return false;
+        }
+
+        // An inheritable attribute can be removed only when
+        // the parent's computed value is the default one too.
+        if is_inherited(aid) {
+            match inherited_value(node, aid) {
+                Some(ref v) => is_same_value(v, &def),
+                None => false,
+            }
+        } else {
+            true
+        }
+    })
+}
+
+/// Removes inheritable attributes that are equal to the parent's computed value.
+pub fn remove_inherited_attributes(root: &Node) -> usize {
+    remove_attributes(root, |node, aid, value| {
+        if !is_inherited(aid) {
+            return false;
+        }
+
+        match inherited_value(node, aid) {
+            Some(ref v) => is_same_value(v, value),
+            None => false,
+        }
+    })
+}
+
+fn remove_attributes<F>(root: &Node, f: F) -> usize
+    where F: Fn(&Node, AId, &AttributeValue) -> bool
+{
+    let mut count = 0;
+    for mut node in root.descendants().filter(|n| n.is_element()) {
+        let ids: Vec<AId> = node.attributes().iter()
+            .filter_map(|attr| {
+                let aid = attr.id()?;
+                if f(&node, aid, &attr.value) { Some(aid) } else { None }
+            })
+            .collect();
+
+        for aid in ids {
+            node.remove_attribute(aid);
+            count += 1;
+        }
+    }
+
+    count
+}
+
+// Returns a computed value of the parent element or a default value when it's not set.
+//
+// Returns `None` when the value depends on a context. Elements referenced by `use`
+// inherit attributes from the `use` element and not from their parents.
+fn inherited_value(node: &Node, aid: AId) -> Option<AttributeValue> {
+    if is_use_target(node) {
+        return None;
+    }
+
+    for n in node.ancestors().skip(1) {
+        match n.attributes().get_value(aid) {
+            Some(&AttributeValue::Inherit) | None => {}
+            Some(v) => return Some(v.clone()),
+        }
+
+        if is_use_target(&n) {
+            return None;
+        }
+    }
+
+    AttributeValue::default_value(aid)
+}
+
+// `visibility` is marked as non-inheritable by `AttributeType`,
+// but descendants with `visibility="visible"` are still rendered inside a hidden group.
+fn is_inherited(aid: AId) -> bool {
+    aid.is_inheritable() || aid == AId::Visibility
+}
+
+fn is_use_target(node: &Node) -> bool {
+    node.linked_nodes().iter().any(|n| n.has_tag_name(ElementId::Use))
+}
+
+// Numbers and unitless lengths are interchangeable.
+fn is_same_value(v1: &AttributeValue, v2: &AttributeValue) -> bool {
+    match (v1, v2) {
+          (&AttributeValue::Length(len), &AttributeValue::Number(n))
+        | (&AttributeValue::Number(n), &AttributeValue::Length(len)) => {
+            len.unit == LengthUnit::None && len.num == n
+        }
+        _ => v1 == v2,
+    }
+}
@@ -1,21 +1,36 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt;
+use std::rc::{Rc, Weak};
 
 use log::warn;
 use slab::Slab;
 
+use crate::cleanup;
+use crate::flatten;
+use crate::ids;
+use crate::import;
+use crate::resolve;
+use crate::ungroup;
 use crate::parser::parse_svg;
 
+use crate::tree::TreeHooks;
 use crate::writer;
 use crate::{
     AttributeQName,
     Attributes,
     AttributeValue,
+    BorrowError,
     ElementId,
+    Error,
     FilterSvg,
     FilterSvgAttrs,
+    LinkGraph,
     Node,
     NodeData,
     NodeType,
+    NormalizeOptions,
+    ParseOptions,
     ParserError,
     QName,
     QNameRef,
@@ -61,6 +76,110 @@ use crate::{
 pub struct Document {
     root: Node,
     storage: Slab<Node>,
+    ids: Rc<RefCell<IdIndex>>,
+}
+
+/// Elements ids index.
+///
+/// Shared between the `Document` and its element nodes, so `Node::set_id`
+/// and the tree modification hooks can keep it up to date.
+///
+/// Only nodes that are part of the document tree are indexed.
+///
+/// Duplicated ids are allowed, so each id maps to a list of nodes
+/// in the insertion order.
+#[derive(Default)]
+pub(crate) struct IdIndex {
+    map: HashMap<String, Vec<Node>>,
+}
+
+impl IdIndex {
+    pub(crate) fn insert(&mut self, id: &str, node: &Node) {
+        if !id.is_empty() {
+            self.map.entry(id.to_string()).or_default().push(node.clone());
+        }
+    }
+
+    pub(crate) fn remove(&mut self, id: &str, node: &Node) {
+        let is_empty = match self.map.get_mut(id) {
+            Some(nodes) => {
+                nodes.retain(|n| n != node);
+                nodes.is_empty()
+            }
+            None => false,
+        };
+
+        if is_empty {
+            self.map.remove(id);
+        }
+    }
+
+    fn get(&self, id: &str) -> Option<Node> {
+        self.map.get(id)?.first().cloned()
+    }
+}
+
+/// Ids index changes caused by the tree modification.
+pub struct IdIndexUpdate {
+    insert: bool,
+    nodes: Vec<(Rc<RefCell<IdIndex>>, String, Node)>,
+}
+
+impl IdIndexUpdate {
+    // Only nodes that are part of the document tree are indexed.
+    fn new(parent: Option<&Node>, node: &Node, insert: bool) -> Result<Self, BorrowError> {
+        let mut nodes = Vec::new();
+        let in_tree = match parent {
+            Some(parent) => is_in_tree(parent)?,
+            None => false,
+        };
+
+        if in_tree {
+            for n in node.try_descendants()? {
+                let data = n.try_borrow()?;
+                if let Some(index) = data.id_index.upgrade() {
+                    if !data.id.is_empty() {
+                        nodes.push((index, data.id.clone(), n.clone()));
+                    }
+                }
+            }
+        }
+
+        Ok(IdIndexUpdate { insert, nodes })
+    }
+}
+
+impl TreeHooks for NodeData {
+    type Update = IdIndexUpdate;
+
+    fn on_detach(node: &Node) -> Result<IdIndexUpdate, BorrowError> {
+        IdIndexUpdate::new(node.try_parent()?.as_ref(), node, false)
+    }
+
+    fn on_insert(parent: Option<&Node>, node: &Node) -> Result<IdIndexUpdate, BorrowError> {
+        IdIndexUpdate::new(parent, node, true)
+    }
+
+    fn apply(update: IdIndexUpdate) {
+        for (index, id, node) in update.nodes {
+            if update.insert {
+                index.borrow_mut().insert(&id, &node);
+            } else {
+                index.borrow_mut().remove(&id, &node);
+            }
+        }
+    }
+}
+
+/// Checks that the node is a part of the document tree and not a detached subtree.
+pub(crate) fn is_in_tree(node: &Node) -> Result<bool, BorrowError> {
+    let mut node = node.clone();
+    while let Some(parent) = node.try_parent()? {
+        node = parent;
+    }
+
+    let is_root = node.try_borrow()?.node_type == NodeType::Root;
+    Ok(is_root)
 }
 
 impl Document {
@@ -75,6 +194,7 @@ impl Document {
             attributes: Attributes::new(),
             linked_nodes: Vec::new(),
             text: String::new(),
+            id_index: Weak::new(),
         });
 
         let key = storage.insert(root.clone());
@@ -83,16 +203,26 @@ impl Document {
         Document {
             root,
             storage,
+            ids: Rc::new(RefCell::new(IdIndex::default())),
         }
     }
 
-    /// Constructs a new `Document` from the text.
+    /// Constructs a new `Document` from the text using a default [`ParseOptions`].
     ///
     /// [`ParseOptions`]: struct.ParseOptions.html
     ///
     /// **Note:** only SVG elements and attributes will be parsed.
     pub fn from_str(text: &str) -> Result<Document, ParserError> {
-        parse_svg(text)
+        Document::from_str_with_opt(text, &ParseOptions::default())
+    }
+
+    /// Constructs a new `Document` from the text using a supplied [`ParseOptions`].
+    ///
+    /// [`ParseOptions`]: struct.ParseOptions.html
+    ///
+    /// **Note:** only SVG elements and attributes will be parsed.
+    pub fn from_str_with_opt(text: &str, opt: &ParseOptions) -> Result<Document, ParserError> {
+        parse_svg(text, opt)
     }
 
     /// Writes a `Document` content to a string.
@@ -128,6 +258,7 @@ impl Document {
             attributes: Attributes::new(),
             linked_nodes: Vec::new(),
             text: String::new(),
+            id_index: Rc::downgrade(&self.ids),
         });
 
         let key = self.storage.insert(node.clone());
@@ -157,6 +288,7 @@ impl Document {
             attributes: Attributes::new(),
             linked_nodes: Vec::new(),
             text: text.into(),
+            id_index: Weak::new(),
         });
 
         let key = self.storage.insert(node.clone());
@@ -202,6 +334,57 @@ impl Document {
         None
     }
 
+    /// Returns an element with the specified id.
+    ///
+    /// If there are multiple elements with the same id, the first one
+    /// that received it will be returned. For a parsed document,
+    /// it's the first one in the document order.
+    ///
+    /// Ids are indexed, so this method doesn't traverse the tree.
+    /// Elements that are not a part of the tree, like detached ones, are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::{Document, ElementId};
+    ///
+    /// let doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <rect id='rect1'/>
+    /// </svg>").unwrap();
+    ///
+    /// let rect = doc.node_by_id("rect1").unwrap();
+    /// assert_eq!(rect.has_tag_name(ElementId::Rect), true);
+    /// assert_eq!(doc.node_by_id("rect2"), None);
+    /// ```
+    pub fn node_by_id(&self, id: &str) -> Option<Node> {
+        self.ids.borrow().get(id)
+    }
+
+    /// Returns a graph of all links in the document.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::{Document, AttributeId};
+    ///
+    /// let doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    ///     <linearGradient id='lg1'/>
+    ///     <linearGradient id='lg2' xlink:href='#lg1'/>
+    ///     <rect fill='url(#lg2)' stroke='url(#lg1)'/>
+    /// </svg>").unwrap();
+    ///
+    /// let graph = doc.link_graph();
+    /// assert_eq!(graph.edges().len(), 3);
+    ///
+    /// let edge = &graph.edges()[0];
+    /// assert_eq!(*edge.source.id(), "lg2");
+    /// assert_eq!(edge.attribute.has_id(AttributeId::Href), true);
+    /// assert_eq!(*edge.target.id(), "lg1");
+    /// ```
+    pub fn link_graph(&self) -> LinkGraph {
+        LinkGraph::new(&self.root)
+    }
+
     /// Removes this node and all it children from the tree.
     ///
     /// Same as `detach()`, but also removes all linked attributes from the tree.
@@ -327,6 +510,502 @@ impl Document {
         }
     }
 
+    /// Replaces all basic shapes in the document with equivalent `path` elements.
+    ///
+    /// Returns the number of converted shapes.
+    ///
+    /// See [`Node::convert_to_path()`](struct.Node.html#method.convert_to_path) for details.
+    pub fn convert_shapes_to_paths(&mut self) -> usize {
+        let mut count = 0;
+        for mut node in self.root().descendants() {
+            if node.convert_to_path() {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Normalizes the path data of all nodes in the document.
+    ///
+    /// Returns the number of processed nodes.
+    ///
+    /// See [`NormalizeOptions`](struct.NormalizeOptions.html) for details.
+    pub fn normalize_paths(&mut self, opt: &NormalizeOptions) -> usize {
+        let mut count = 0;
+        for mut node in self.root().descendants() {
+            if node.normalize_path(opt) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Applies transforms to the elements geometry.
+    ///
+    /// - `g` transforms are pushed down to its children.
+    /// - Path data, `line`, `polyline` and `polygon` are transformed directly.
+    /// - `rect` and `ellipse` are transformed only on translate and scale,
+    ///   `circle` on translate, rotate and proportional scale.
+    /// - `gradientTransform` is updated when the gradient is used only by this element.
+    /// - `stroke-width`, `stroke-dasharray` and `stroke-dashoffset` are scaled
+    ///   when the scale is proportional.
+    ///
+    /// Transforms that cannot be applied without changing the rendering are preserved.
+    /// For example, on a `text`, `use` or `image` elements, on elements with markers,
+    /// `clip-path`, `mask` or `filter` and on elements with a non-uniformly scaled stroke.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <g transform='translate(10 20)'>
+    ///         <rect width='10' height='20' transform='scale(2)'/>
+    ///     </g>
+    /// </svg>").unwrap();
+    ///
+    /// doc.flatten_transforms();
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <g>
+    ///         <rect height=\"40\" width=\"20\" x=\"10\" y=\"20\"/>
+    ///     </g>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn flatten_transforms(&mut self) {
+        flatten::flatten_transforms(&self.root());
+    }
+
+    /// Removes redundant groups.
+    ///
+    /// - Groups without attributes are replaced with their children.
+    /// - Group attributes are moved into the only child when they are not conflicting.
+    ///   Inherited attributes are overridden by the child ones, `transform` and `opacity`
+    ///   are combined.
+    ///
+    /// Groups that are referenced by other elements, direct children of `switch`
+    /// and groups with `clip-path`, `mask` or `filter` are preserved.
+    /// Ids of removed groups are lost.
+    ///
+    /// Returns the number of removed groups.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <g>
+    ///         <g fill='red' opacity='0.5'>
+    ///             <rect fill='green' opacity='0.5' width='10' height='20'/>
+    ///         </g>
+    ///     </g>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.ungroup_groups(), 2);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <rect fill=\"#008000\" height=\"20\" opacity=\"0.25\" width=\"10\"/>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn ungroup_groups(&mut self) -> usize {
+        ungroup::ungroup_groups(self)
+    }
+
+    /// Renames all non-empty ids using the provided function.
+    ///
+    /// Links are stored as nodes, so they will be serialized using new ids.
+    ///
+    /// An empty id will remove it.
+    ///
+    /// # Errors
+    ///
+    /// Nothing will be renamed if:
+    ///
+    /// - new ids are not unique. `Error::DuplicatedId` will be returned.
+    /// - a new id of a linked element is empty. `Error::ElementMustHaveAnId` will be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <linearGradient id='lg1'/>
+    ///     <rect fill='url(#lg1)'/>
+    /// </svg>").unwrap();
+    ///
+    /// doc.rename_ids(|id| format!("prefix-{}", id)).unwrap();
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <linearGradient id=\"prefix-lg1\"/>
+    ///     <rect fill=\"url(#prefix-lg1)\"/>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn rename_ids<F>(&mut self, f: F) -> Result<(), Error>
+        where F: FnMut(&str) -> String
+    {
+        ids::rename_ids(&self.root(), f)
+    }
+
+    /// Replaces all non-empty ids with the shortest possible ones.
+    ///
+    /// Ids are assigned in order of use frequency, so the most used elements
+    /// will get the shortest ids. Unused ids are renamed too,
+    /// use [`remove_unused_ids()`](#method.remove_unused_ids) to remove them.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <linearGradient id='lg1'/>
+    ///     <linearGradient id='lg2'/>
+    ///     <rect fill='url(#lg1)'/>
+    ///     <rect fill='url(#lg2)' stroke='url(#lg2)'/>
+    /// </svg>").unwrap();
+    ///
+    /// doc.minify_ids();
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <linearGradient id=\"b\"/>
+    ///     <linearGradient id=\"a\"/>
+    ///     <rect fill=\"url(#b)\"/>
+    ///     <rect fill=\"url(#a)\" stroke=\"url(#a)\"/>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn minify_ids(&mut self) {
+        ids::minify_ids(&self.root())
+    }
+
+    /// Renames elements with duplicated ids.
+    ///
+    /// The first element in the document order keeps its id and the following
+    /// ones get a numeric suffix. Since links are resolved to the first element,
+    /// this makes the serialized document equal to the parsed one.
+    ///
+    /// Returns the number of renamed elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <rect id='rect1'/>
+    ///     <rect id='rect1'/>
+    ///     <rect id='rect1-1'/>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.make_ids_unique(), 1);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <rect id=\"rect1\"/>
+    ///     <rect id=\"rect1-2\"/>
+    ///     <rect id=\"rect1-1\"/>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn make_ids_unique(&mut self) -> usize {
+        ids::make_ids_unique(&self.root())
+    }
+
+    /// Removes unused referenced elements.
+    ///
+    /// Removes [referenced elements], like gradients, patterns and markers,
+    /// and graphic elements inside `defs` that are not used by any element
+    /// outside their own subtree.
+    ///
+    /// The removing is repeated until there are no unused elements left,
+    /// because removed elements may be the only users of other elements.
+    ///
+    /// Returns the number of removed elements, not including their children.
+    ///
+    /// [referenced elements]: trait.ElementType.html#tymethod.is_referenced
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    ///     <linearGradient id='lg1'/>
+    ///     <linearGradient id='lg2' xlink:href='#lg1'/>
+    ///     <linearGradient id='lg3'/>
+    ///     <rect fill='url(#lg3)'/>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.remove_unused_defs(), 2);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <linearGradient id=\"lg3\"/>
+    ///     <rect fill=\"url(#lg3)\"/>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn remove_unused_defs(&mut self) -> usize {
+        cleanup::remove_unused_defs(self)
+    }
+
+    /// Removes ids that are not used by any links.
+    ///
+    /// Returns the number of removed ids.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <linearGradient id='lg1'/>
+    ///     <rect id='rect1' fill='url(#lg1)'/>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.remove_unused_ids(), 1);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <linearGradient id=\"lg1\"/>
+    ///     <rect fill=\"url(#lg1)\"/>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn remove_unused_ids(&mut self) -> usize {
+        cleanup::remove_unused_ids(&self.root())
+    }
+
+    /// Removes duplicated referenced elements.
+    ///
+    /// [Referenced elements], like gradients, filters and clip paths, are compared
+    /// structurally: by the tag name, attributes and children, ignoring ids.
+    /// All links to a duplicate are replaced with links to the first equal element
+    /// in the document order and the duplicate is removed.
+    ///
+    /// Elements with children that are used outside of them are preserved.
+    /// Elements are also preserved when their ancestors have different
+    /// inheritable attributes, because the content would be rendered differently.
+    ///
+    /// Returns the number of removed elements.
+    ///
+    /// [Referenced elements]: trait.ElementType.html#tymethod.is_referenced
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <linearGradient id='lg1'>
+    ///         <stop offset='0.5'/>
+    ///     </linearGradient>
+    ///     <linearGradient id='lg2'>
+    ///         <stop offset='0.5'/>
+    ///     </linearGradient>
+    ///     <rect fill='url(#lg1)'/>
+    ///     <rect fill='url(#lg2)'/>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.remove_duplicated_defs(), 1);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <linearGradient id=\"lg1\">
+    ///         <stop offset=\"0.5\"/>
+    ///     </linearGradient>
+    ///     <rect fill=\"url(#lg1)\"/>
+    ///     <rect fill=\"url(#lg1)\"/>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn remove_duplicated_defs(&mut self) -> usize {
+        cleanup::remove_duplicated_defs(self)
+    }
+
+    /// Removes attributes that are equal to their default values.
+    ///
+    /// Inheritable attributes are removed only when the parent's computed value
+    /// is the default one too. Attributes of elements referenced by `use`
+    /// are preserved, since they can inherit values from the `use` element.
+    ///
+    /// Only attributes with a known [default value] are affected.
+    ///
+    /// Returns the number of removed attributes.
+    ///
+    /// [default value]: enum.AttributeValue.html#method.default_value
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <g fill='red' opacity='1'>
+    ///         <rect fill='black' stroke='none'/>
+    ///     </g>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.remove_default_attributes(), 2);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <g fill=\"#ff0000\">
+    ///         <rect fill=\"#000000\"/>
+    ///     </g>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn remove_default_attributes(&mut self) -> usize {
+        cleanup::remove_default_attributes(&self.root())
+    }
+
+    /// Removes inheritable attributes that are equal to the parent's computed value.
+    ///
+    /// The computed value is the value set on the nearest ancestor
+    /// or the default value when none of the ancestors have it.
+    /// Attributes of elements referenced by `use` are preserved, since they can inherit
+    /// values from the `use` element.
+    ///
+    /// Returns the number of removed attributes.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <g fill='red' opacity='0.5'>
+    ///         <rect fill='red' opacity='0.5'/>
+    ///     </g>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.remove_inherited_attributes(), 1);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <g fill=\"#ff0000\" opacity=\"0.5\">
+    ///         <rect opacity=\"0.5\"/>
+    ///     </g>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn remove_inherited_attributes(&mut self) -> usize {
+        cleanup::remove_inherited_attributes(&self.root())
+    }
+
+    /// Replaces all `use` elements with the referenced content.
+    ///
+    /// See [`resolve_use_node()`](#method.resolve_use_node) for details.
+    ///
+    /// Referenced elements are preserved, use
+    /// [`remove_unused_defs()`](#method.remove_unused_defs) to remove them.
+    ///
+    /// Returns the number of resolved `use` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    ///     <defs>
+    ///         <rect id='rect1' width='10' height='20'/>
+    ///     </defs>
+    ///     <use x='5' xlink:href='#rect1'/>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.resolve_use(), 1);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <defs>
+    ///         <rect id=\"rect1\" height=\"20\" width=\"10\"/>
+    ///     </defs>
+    ///     <g transform=\"matrix(1 0 0 1 5 0)\">
+    ///         <rect height=\"20\" width=\"10\"/>
+    ///     </g>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn resolve_use(&mut self) -> usize {
+        resolve::resolve_use(self)
+    }
+
+    /// Replaces a `use` element with the referenced content.
+    ///
+    /// - The `use` element is converted into a `g` element, so its id,
+    ///   presentation attributes and incoming links are preserved.
+    /// - `x` and `y` are converted into an additional translate transform.
+    /// - The referenced element is copied via [`copy_node_deep()`](#method.copy_node_deep).
+    /// - `svg` and `symbol` elements are copied as groups. Their viewport is converted
+    ///   into a transform and a `clipPath`, unless `overflow` is `visible` or `auto`.
+    /// - `width` and `height` of the `use` element override the viewport size.
+    /// - Nested `use` elements inside the copied content are resolved too.
+    /// - At most 100 000 nodes are copied, so `use` chains cannot grow exponentially.
+    ///
+    /// Returns `false` when the node is not a `use` element, when the reference
+    /// is not resolved, when it's recursive or when the copy limit is reached.
+    pub fn resolve_use_node(&mut self, node: Node) -> bool {
+        resolve::resolve_use_node(self, node) != 0
+    }
+
+    /// Resolves `xlink:href` inheritance of gradients and patterns.
+    ///
+    /// Attributes that are not set on the element are copied from the referenced
+    /// elements, following the whole `xlink:href` chain. Gradients without stops
+    /// and patterns without children get a copy of the referenced element children.
+    /// The `xlink:href` attribute is removed afterwards.
+    ///
+    /// Gradients can inherit `gradientUnits`, `gradientTransform` and `spreadMethod`
+    /// from any gradient, but geometry attributes only from the gradient of the same type.
+    ///
+    /// Referenced elements are preserved, use
+    /// [`remove_unused_defs()`](#method.remove_unused_defs) to remove them.
+    ///
+    /// Returns the number of resolved elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    ///     <linearGradient id='lg1' x1='1'>
+    ///         <stop offset='0'/>
+    ///     </linearGradient>
+    ///     <linearGradient id='lg2' x1='0' x2='0' xlink:href='#lg1'/>
+    /// </svg>").unwrap();
+    ///
+    /// assert_eq!(doc.resolve_href_inheritance(), 1);
+    ///
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <linearGradient id=\"lg1\" x1=\"1\">
+    ///         <stop offset=\"0\"/>
+    ///     </linearGradient>
+    ///     <linearGradient id=\"lg2\" x1=\"0\" x2=\"0\">
+    ///         <stop offset=\"0\"/>
+    ///     </linearGradient>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn resolve_href_inheritance(&mut self) -> usize {
+        resolve::resolve_href_inheritance(self)
+    }
+
     /// Returns a copy of a current node without children.
     ///
     /// All attributes except `id` will be copied, because `id` must be unique.
@@ -356,6 +1035,156 @@ impl Document {
         root
     }
 
+    /// Returns a deep copy of a current node with all it's children, including ids.
+    ///
+    /// Unlike [`copy_node_deep`], the copied elements will get new unique ids,
+    /// based on the original ones. Links between the copied elements will point
+    /// to the copies, while links to other elements will remain unchanged.
+    ///
+    /// The returned node is detached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgdom::{Document, WriteOptions};
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <g id='g1'>
+    ///         <clipPath id='clip1'>
+    ///             <rect width='10' height='10'/>
+    ///         </clipPath>
+    ///         <rect clip-path='url(#clip1)' width='20' height='20'/>
+    ///     </g>
+    /// </svg>").unwrap();
+    ///
+    /// let g = doc.node_by_id("g1").unwrap();
+    /// let new_g = doc.copy_node_deep_with_ids(g);
+    /// doc.svg_element().unwrap().append(new_g);
+    ///
+    /// let mut opt = WriteOptions::default();
+    /// opt.use_single_quote = true;
+    /// assert_eq!(doc.to_string_with_opt(&opt),
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <g id='g1'>
+    ///         <clipPath id='clip1'>
+    ///             <rect height='10' width='10'/>
+    ///         </clipPath>
+    ///         <rect clip-path='url(#clip1)' height='20' width='20'/>
+    ///     </g>
+    ///     <g id='g1-1'>
+    ///         <clipPath id='clip1-1'>
+    ///             <rect height='10' width='10'/>
+    ///         </clipPath>
+    ///         <rect clip-path='url(#clip1-1)' height='20' width='20'/>
+    ///     </g>
+    /// </svg>
+    /// ");
+    /// ```
+    ///
+    /// [`copy_node_deep`]: #method.copy_node_deep
+    pub fn copy_node_deep_with_ids(&mut self, node: Node) -> Node {
+        import::copy_node_deep_with_ids(self, &node)
+    }
+
+    /// Returns a deep copy of the whole document.
+    ///
+    /// Unlike writing and parsing, all the nodes, ids and links are copied as is,
+    /// including detached nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <linearGradient id='lg1'/>
+    ///     <rect fill='url(#lg1)'/>
+    /// </svg>").unwrap();
+    ///
+    /// let new_doc = doc.deep_clone();
+    /// assert_eq!(doc.to_string(), new_doc.to_string());
+    ///
+    /// // Links are pointing to the new nodes.
+    /// let lg = new_doc.node_by_id("lg1").unwrap();
+    /// assert_eq!(lg.uses_count(), 1);
+    /// assert_ne!(lg, doc.node_by_id("lg1").unwrap());
+    /// ```
+    pub fn deep_clone(&self) -> Document {
+        let detached: Vec<Node> = self.storage.iter()
+            .map(|(_, n)| n)
+            .filter(|n| !n.is_root() && n.parent().is_none())
+            .cloned()
+            .collect();
+
+        let mut doc = Document::new();
+        import::clone_document(&mut doc, &self.root, &detached);
+        doc
+    }
+
+    /// Returns a copy of a node from another document.
+    ///
+    /// Unlike [`copy_node`], ids are preserved. Ids that are already used
+    /// in the current document will be renamed.
+    ///
+    /// Elements referenced by the copied nodes, like gradients and clip paths,
+    /// are imported too and appended to the `defs` element of the root `svg` element.
+    /// The `defs` element will be created when missing.
+    /// Links to them are ignored when the current document doesn't have an `svg` element.
+    ///
+    /// The returned node is detached.
+    ///
+    /// # Panics
+    ///
+    /// - If the node doesn't belong to the `other` document.
+    /// - If the node is a root node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgdom::{Document, WriteOptions};
+    ///
+    /// let icons = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <linearGradient id='lg1'/>
+    ///     <rect id='icon' fill='url(#lg1)'/>
+    /// </svg>").unwrap();
+    ///
+    /// let mut doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <rect id='icon'/>
+    /// </svg>").unwrap();
+    ///
+    /// let icon = icons.node_by_id("icon").unwrap();
+    /// let node = doc.import_node(&icons, icon, true);
+    /// doc.svg_element().unwrap().append(node);
+    ///
+    /// let mut opt = WriteOptions::default();
+    /// opt.use_single_quote = true;
+    /// assert_eq!(doc.to_string_with_opt(&opt),
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <defs>
+    ///         <linearGradient id='lg1'/>
+    ///     </defs>
+    ///     <rect id='icon'/>
+    ///     <rect id='icon-1' fill='url(#lg1)'/>
+    /// </svg>
+    /// ");
+    /// ```
+    ///
+    /// [`copy_node`]: #method.copy_node
+    pub fn import_node(&mut self, other: &Document, node: Node, deep: bool) -> Node {
+        let is_owned = match node.borrow().storage_key {
+            Some(key) => other.storage.get(key) == Some(&node),
+            None => false,
+        };
+        assert!(is_owned, "the node doesn't belong to the other document");
+        assert!(!node.is_root(), "the root node cannot be imported");
+
+        import::import_node(self, &node, deep)
+    }
+
     fn _make_deep_copy(&mut self, parent: &mut Node, node: &Node) {
         for child in node.children() {
             let mut new_node = self.copy_node(child.clone());
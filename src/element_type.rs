@@ -20,6 +20,7 @@ pub trait ElementType {
     ///
     /// List: `altGlyphDef`, `clipPath`, `cursor`, `filter`, `linearGradient`, `marker`,
     /// `mask`, `pattern`, `radialGradient` and `symbol`.
+    /// And SVG 2 paint servers: `hatch`, `mesh`, `meshgradient` and `solidcolor`.
     ///
     /// Details: <https://www.w3.org/TR/SVG/struct.html#Head>
     ///
@@ -89,6 +90,7 @@ pub trait ElementType {
     /// Returns true if the current node is a [paint server].
     ///
     /// List: `linearGradient`, `radialGradient` and `pattern`.
+    /// And SVG 2 paint servers: `hatch`, `mesh`, `meshgradient` and `solidcolor`.
     ///
     /// [paint server]: <https://www.w3.org/TR/SVG11/pservers.html#Introduction>
     fn is_paint_server(&self) -> bool;
@@ -125,11 +127,15 @@ impl ElementType for Node {
         | ElementId::ClipPath
         | ElementId::Cursor
         | ElementId::Filter
+        | ElementId::Hatch
         | ElementId::LinearGradient
         | ElementId::Marker
         | ElementId::Mask
+        | ElementId::Mesh
+        | ElementId::Meshgradient
         | ElementId::Pattern
         | ElementId::RadialGradient
+        | ElementId::Solidcolor
         | ElementId::Symbol);
 
     is_func!(is_basic_shape,
@@ -194,7 +200,11 @@ impl ElementType for Node {
     is_func!(is_paint_server,
           ElementId::LinearGradient
         | ElementId::RadialGradient
-        | ElementId::Pattern);
+        | ElementId::Pattern
+        | ElementId::Hatch
+        | ElementId::Mesh
+        | ElementId::Meshgradient
+        | ElementId::Solidcolor);
 
     is_func!(is_filter_primitive,
           ElementId::FeBlend
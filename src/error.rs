@@ -24,7 +24,28 @@ pub enum Error {
     /// ```text
     /// <linearGradient id="lg1" xlink:href="#lg1"/>
     /// ```
+    ///
+    /// Indirect cycles are detected too. Including cycles through the element's
+    /// children, since an element depends on all links of its subtree.
+    ///
+    /// ```text
+    /// <rect id="rect1" mask="url(#mask1)"/>
+    /// <mask id="mask1">
+    ///     <use xlink:href="#rect1"/>
+    /// </mask>
+    /// ```
     ElementCrosslink,
+
+    /// An element id must be unique.
+    DuplicatedId(String),
+
+    /// One of the nodes affected by the operation is currently borrowed.
+    ///
+    /// See [`BorrowError`](struct.BorrowError.html).
+    NodeBorrowed,
+
+    /// A node cannot be inserted into itself or into its own subtree.
+    RecursiveInsertion,
 }
 
 impl fmt::Display for Error {
@@ -36,6 +57,15 @@ impl fmt::Display for Error {
             Error::ElementCrosslink => {
                 write!(f, "element crosslink")
             }
+            Error::DuplicatedId(ref id) => {
+                write!(f, "duplicated id '{}'", id)
+            }
+            Error::NodeBorrowed => {
+                write!(f, "the node is already borrowed")
+            }
+            Error::RecursiveInsertion => {
+                write!(f, "a node cannot be inserted into its own subtree")
+            }
         }
     }
 }
@@ -46,6 +76,33 @@ impl error::Error for Error {
     }
 }
 
+impl From<BorrowError> for Error {
+    fn from(_: BorrowError) -> Self {
+        Error::NodeBorrowed
+    }
+}
+
+
+/// A node borrowing error.
+///
+/// Node data is stored in a `RefCell`, so most of the `Node` methods will panic
+/// when the node is already borrowed. For example, when a reference returned by
+/// `Node::attributes` is still alive. The `try_*` methods will return this error instead.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BorrowError;
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the node is already borrowed")
+    }
+}
+
+impl error::Error for BorrowError {
+    fn description(&self) -> &str {
+        "a node borrowing error"
+    }
+}
+
 
 /// SVG parsing errors.
 #[derive(Debug)]
@@ -0,0 +1,332 @@
+// Transforms flattening.
+
+use crate::{
+    AttributeId as AId,
+    AttributeValue,
+    ElementId,
+    ElementType,
+    FuzzyZero,
+    Length,
+    LengthList,
+    Node,
+    Path,
+    Points,
+    Transform,
+};
+use crate::bbox::{
+    find_inherited,
+    path_bbox,
+    resolve_stroke_width,
+};
+use crate::geom::{
+    invert_transform,
+    is_similarity,
+    Rect,
+};
+use crate::path::transform_path;
+use crate::shapes::{
+    resolve_rx_ry,
+    shape_to_path,
+};
+use crate::units::{
+    convert_length,
+    resolve_length,
+};
+
+/// Pushes group transforms down to the children and applies transforms to the geometry.
+pub fn flatten_transforms(root: &Node) {
+    // Descendants are visited in the document order,
+    // so children will receive their parent's transform before being processed.
+    for mut node in root.descendants() {
+        let ts = match node.attributes().get_value(AId::Transform) {
+            Some(&AttributeValue::Transform(ts)) => ts,
+            _ => continue,
+        };
+
+        let is_flattened = if ts.is_default() {
+            true
+        } else if node.has_tag_name(ElementId::G) {
+            push_down(&node, &ts)
+        } else {
+            apply_transform(&mut node, &ts)
+        };
+
+        if is_flattened {
+            node.remove_attribute(AId::Transform);
+        }
+    }
+}
+
+fn push_down(node: &Node, ts: &Transform) -> bool {
+    // Those attributes are defined in the group's coordinate system.
+    if has_any_attribute(node, &[AId::ClipPath, AId::Mask, AId::Filter]) {
+        return false;
+    }
+
+    // A nested `svg` element can't have a transform.
+    if node.children().any(|c| c.has_tag_name(ElementId::Svg)) {
+        return false;
+    }
+
+    for mut child in node.children() {
+        let is_transformable = child.is_graphic() || match child.tag_id() {
+            Some(ElementId::G) | Some(ElementId::A)
+            | Some(ElementId::Switch) | Some(ElementId::ForeignObject) => true,
+            _ => false,
+        };
+
+        // Non-rendering elements like gradients are not affected by the group's transform.
+        if !is_transformable {
+            continue;
+        }
+
+        let mut child_ts = *ts;
+        if let Some(&AttributeValue::Transform(t)) = child.attributes().get_value(AId::Transform) {
+            child_ts.append(&t);
+        }
+
+        child.set_attribute((AId::Transform, child_ts));
+    }
+
+    true
+}
+
+// Returns `false` when the transform cannot be applied without changing the rendering.
+fn apply_transform(node: &mut Node, ts: &Transform) -> bool {
+    let eid = match node.tag_id() {
+        Some(eid) => eid,
+        None => return false,
+    };
+
+    if !node.is_shape() {
+        return false;
+    }
+
+    // Those attributes are defined in the element's coordinate system.
+    if has_any_attribute(node, &[AId::ClipPath, AId::Mask, AId::Filter, AId::VectorEffect]) {
+        return false;
+    }
+
+    // Markers are rendered in the element's coordinate system too.
+    for aid in &[AId::MarkerStart, AId::MarkerMid, AId::MarkerEnd] {
+        if let Some(AttributeValue::FuncLink(_)) = find_inherited(node, *aid) {
+            return false;
+        }
+    }
+
+    // A stroke can be scaled only proportionally.
+    let stroke_width = resolve_stroke_width(node);
+    if stroke_width.is_some() && !is_similarity(ts) {
+        return false;
+    }
+
+    let is_representable = match eid {
+        ElementId::Rect | ElementId::Ellipse => ts.b.is_fuzzy_zero() && ts.c.is_fuzzy_zero(),
+        ElementId::Circle => is_similarity(ts),
+        _ => true,
+    };
+
+    if !is_representable {
+        return false;
+    }
+
+    let path = match eid {
+        ElementId::Path => {
+            match node.attributes().get_value(AId::D) {
+                Some(AttributeValue::Path(path)) => path.clone(),
+                _ => return false,
+            }
+        }
+        _ => {
+            match shape_to_path(node) {
+                Some(path) => path,
+                None => return false,
+            }
+        }
+    };
+
+    let mut gradients: Vec<(Node, Transform)> = Vec::new();
+    for aid in &[AId::Fill, AId::Stroke] {
+        if let Some(AttributeValue::Paint(link, _)) = find_inherited(node, *aid) {
+            if gradients.iter().any(|(n, _)| *n == link) {
+                continue;
+            }
+
+            // A paint server can be updated only when it's used exclusively by this element.
+            if !link.is_gradient() || link.linked_nodes().iter().any(|n| *n != *node) {
+                return false;
+            }
+
+            match gradient_transform(&link, &path, ts) {
+                Some(gts) => gradients.push((link, gts)),
+                None => return false,
+            }
+        }
+    }
+
+    apply_to_geometry(node, eid, path, ts);
+
+    for (mut gradient, gts) in gradients {
+        gradient.set_attribute((AId::GradientTransform, gts));
+    }
+
+    if let Some(width) = stroke_width {
+        let scale = (ts.a * ts.d - ts.b * ts.c).abs().sqrt();
+        scale_stroke(node, width, scale);
+    }
+
+    true
+}
+
+fn apply_to_geometry(node: &mut Node, eid: ElementId, mut path: Path, ts: &Transform) {
+    match eid {
+        ElementId::Path => {
+            transform_path(&mut path, ts);
+            node.set_attribute((AId::D, path));
+        }
+        ElementId::Rect => {
+            let x = resolve_length(node, AId::X, 0.0);
+            let y = resolve_length(node, AId::Y, 0.0);
+            let w = resolve_length(node, AId::Width, 0.0);
+            let h = resolve_length(node, AId::Height, 0.0);
+            let (rx, ry) = resolve_rx_ry(node, w, h);
+
+            // Mirroring produces a negative size.
+            let r = Rect::from_ltrb(
+                ts.a * x + ts.e, ts.d * y + ts.f,
+                ts.a * (x + w) + ts.e, ts.d * (y + h) + ts.f,
+            );
+            let r = Rect::from_ltrb(r.left().min(r.right()), r.top().min(r.bottom()),
+                                    r.left().max(r.right()), r.top().max(r.bottom()));
+
+            set_number(node, AId::X, r.x);
+            set_number(node, AId::Y, r.y);
+            set_number(node, AId::Width, r.width);
+            set_number(node, AId::Height, r.height);
+
+            if rx > 0.0 && ry > 0.0 {
+                set_number(node, AId::Rx, rx * ts.a.abs());
+                set_number(node, AId::Ry, ry * ts.d.abs());
+            } else {
+                node.remove_attribute(AId::Rx);
+                node.remove_attribute(AId::Ry);
+            }
+        }
+        ElementId::Circle => {
+            let (cx, cy) = ts.apply(resolve_length(node, AId::Cx, 0.0), resolve_length(node, AId::Cy, 0.0));
+            let r = resolve_length(node, AId::R, 0.0) * (ts.a * ts.a + ts.b * ts.b).sqrt();
+            set_number(node, AId::Cx, cx);
+            set_number(node, AId::Cy, cy);
+            set_number(node, AId::R, r);
+        }
+        ElementId::Ellipse => {
+            let (cx, cy) = ts.apply(resolve_length(node, AId::Cx, 0.0), resolve_length(node, AId::Cy, 0.0));
+            let rx = resolve_length(node, AId::Rx, 0.0) * ts.a.abs();
+            let ry = resolve_length(node, AId::Ry, 0.0) * ts.d.abs();
+            set_number(node, AId::Cx, cx);
+            set_number(node, AId::Cy, cy);
+            set_number(node, AId::Rx, rx);
+            set_number(node, AId::Ry, ry);
+        }
+        ElementId::Line => {
+            let (x1, y1) = ts.apply(resolve_length(node, AId::X1, 0.0), resolve_length(node, AId::Y1, 0.0));
+            let (x2, y2) = ts.apply(resolve_length(node, AId::X2, 0.0), resolve_length(node, AId::Y2, 0.0));
+            set_number(node, AId::X1, x1);
+            set_number(node, AId::Y1, y1);
+            set_number(node, AId::X2, x2);
+            set_number(node, AId::Y2, y2);
+        }
+          ElementId::Polyline
+        | ElementId::Polygon => {
+            let points: Vec<(f64, f64)> = path.iter()
+                .filter_map(|seg| match (seg.x(), seg.y()) {
+                    (Some(x), Some(y)) => Some(ts.apply(x, y)),
+                    _ => None,
+                })
+                .collect();
+            node.set_attribute((AId::Points, Points(points)));
+        }
+        _ => {}
+    }
+}
+
+fn scale_stroke(node: &mut Node, width: f64, scale: f64) {
+    set_number(node, AId::StrokeWidth, width * scale);
+
+    if let Some(AttributeValue::LengthList(list)) = find_inherited(node, AId::StrokeDasharray) {
+        let list: Vec<Length> = list.iter().map(|len| {
+            Length::new_number(convert_length(*len, node, AId::StrokeDasharray) * scale)
+        }).collect();
+        node.set_attribute((AId::StrokeDasharray, LengthList(list)));
+    }
+
+    if let Some(AttributeValue::Length(len)) = find_inherited(node, AId::StrokeDashoffset) {
+        let offset = convert_length(len, node, AId::StrokeDashoffset) * scale;
+        set_number(node, AId::StrokeDashoffset, offset);
+    }
+}
+
+// Calculates a new `gradientTransform` for a gradient used by a transformed element.
+fn gradient_transform(gradient: &Node, path: &Path, ts: &Transform) -> Option<Transform> {
+    let gts = match find_gradient_attribute(gradient, AId::GradientTransform) {
+        Some(AttributeValue::Transform(gts)) => gts,
+        _ => Transform::default(),
+    };
+
+    let is_user_space = match find_gradient_attribute(gradient, AId::GradientUnits) {
+        Some(AttributeValue::String(ref units)) => units == "userSpaceOnUse",
+        _ => false,
+    };
+
+    let mut new_ts;
+    if is_user_space {
+        new_ts = *ts;
+    } else {
+        // `objectBoundingBox` units are relative to the element's bounding box,
+        // which will be changed after the transformation.
+        let old_bbox = path_bbox(path, &Transform::default())?;
+        let new_bbox = path_bbox(path, ts)?;
+        if old_bbox.width.is_fuzzy_zero() || old_bbox.height.is_fuzzy_zero()
+            || new_bbox.width.is_fuzzy_zero() || new_bbox.height.is_fuzzy_zero()
+        {
+            return None;
+        }
+
+        new_ts = invert_transform(&bbox_transform(&new_bbox))?;
+        new_ts.append(ts);
+        new_ts.append(&bbox_transform(&old_bbox));
+    }
+
+    new_ts.append(&gts);
+    Some(new_ts)
+}
+
+fn bbox_transform(r: &Rect) -> Transform {
+    Transform::new(r.width, 0.0, 0.0, r.height, r.x, r.y)
+}
+
+// Gradient attributes can be inherited from a referenced gradient via `xlink:href`.
+fn find_gradient_attribute(gradient: &Node, aid: AId) -> Option<AttributeValue> {
+    let mut node = gradient.clone();
+    loop {
+        if let Some(v) = node.attributes().get_value(aid) {
+            return Some(v.clone());
+        }
+
+        let link = match node.attributes().get_value(AId::Href) {
+            Some(AttributeValue::Link(link)) if link.is_gradient() => link.clone(),
+            _ => return None,
+        };
+
+        node = link;
+    }
+}
+
+fn has_any_attribute(node: &Node, list: &[AId]) -> bool {
+    let attrs = node.attributes();
+    list.iter().any(|aid| attrs.contains(*aid))
+}
+
+fn set_number(node: &mut Node, aid: AId, n: f64) {
+    node.set_attribute((aid, Length::new_number(n)));
+}
@@ -0,0 +1,229 @@
+use std::f64;
+
+use crate::{
+    FuzzyEq,
+    FuzzyZero,
+    Transform,
+};
+
+/// A rectangle.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// Constructs a new `Rect`.
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Rect { x, y, width, height }
+    }
+
+    /// Constructs a new `Rect` from edges.
+    pub fn from_ltrb(left: f64, top: f64, right: f64, bottom: f64) -> Self {
+        Rect::new(left, top, right - left, bottom - top)
+    }
+
+    /// Returns the left edge.
+    pub fn left(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the right edge.
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    /// Returns the top edge.
+    pub fn top(&self) -> f64 {
+        self.y
+    }
+
+    /// Returns the bottom edge.
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// Returns a rectangle that contains both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::from_ltrb(
+            self.left().min(other.left()),
+            self.top().min(other.top()),
+            self.right().max(other.right()),
+            self.bottom().max(other.bottom()),
+        )
+    }
+
+    /// Returns a bounding box of the transformed rectangle.
+    pub fn transform(&self, ts: &Transform) -> Rect {
+        let mut bbox = BBox::new();
+        bbox.add_point(ts.apply(self.left(), self.top()));
+        bbox.add_point(ts.apply(self.right(), self.top()));
+        bbox.add_point(ts.apply(self.right(), self.bottom()));
+        bbox.add_point(ts.apply(self.left(), self.bottom()));
+        bbox.to_rect().unwrap()
+    }
+}
+
+impl FuzzyEq for Rect {
+    fn fuzzy_eq(&self, other: &Self) -> bool {
+           self.x.fuzzy_eq(&other.x)
+        && self.y.fuzzy_eq(&other.y)
+        && self.width.fuzzy_eq(&other.width)
+        && self.height.fuzzy_eq(&other.height)
+    }
+}
+
+// Returns an inverted transform or `None` when it's not invertible.
+pub(crate) fn invert_transform(ts: &Transform) -> Option<Transform> {
+    let det = ts.a * ts.d - ts.b * ts.c;
+    if det.is_fuzzy_zero() {
+        return None;
+    }
+
+    Some(Transform::new(
+        ts.d / det,
+        -ts.b / det,
+        -ts.c / det,
+        ts.a / det,
+        (ts.c * ts.f - ts.d * ts.e) / det,
+        (ts.b * ts.e - ts.a * ts.f) / det,
+    ))
+}
+
+// Checks that the transform preserves shapes, i.e. contains only
+// translate, rotate, proportional scale and mirroring.
+pub(crate) fn is_similarity(ts: &Transform) -> bool {
+       (ts.a * ts.c + ts.b * ts.d).is_fuzzy_zero()
+    && (ts.a * ts.a + ts.b * ts.b).fuzzy_eq(&(ts.c * ts.c + ts.d * ts.d))
+}
+
+// A bounding box accumulator.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BBox {
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+}
+
+impl BBox {
+    pub fn new() -> Self {
+        BBox {
+            left: f64::MAX,
+            top: f64::MAX,
+            right: f64::MIN,
+            bottom: f64::MIN,
+        }
+    }
+
+    pub fn add_point(&mut self, (x, y): (f64, f64)) {
+        self.add_x(x);
+        self.add_y(y);
+    }
+
+    pub fn add_x(&mut self, x: f64) {
+        self.left = self.left.min(x);
+        self.right = self.right.max(x);
+    }
+
+    pub fn add_y(&mut self, y: f64) {
+        self.top = self.top.min(y);
+        self.bottom = self.bottom.max(y);
+    }
+
+    pub fn to_rect(self) -> Option<Rect> {
+        if self.left > self.right || self.top > self.bottom {
+            return None;
+        }
+
+        Some(Rect::from_ltrb(self.left, self.top, self.right, self.bottom))
+    }
+}
+
+// An elliptical arc in the center parameterization.
+//
+// https://www.w3.org/TR/SVG11/implnote.html#ArcImplementationNotes
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CenterArc {
+    pub cx: f64,
+    pub cy: f64,
+    pub rx: f64,
+    pub ry: f64,
+    pub sin_phi: f64,
+    pub cos_phi: f64,
+    pub theta1: f64,
+    pub dtheta: f64,
+}
+
+impl CenterArc {
+    // Converts an arc from the endpoint parameterization.
+    //
+    // Returns `None` when the arc should be treated as a straight line
+    // or omitted at all.
+    pub fn new(
+        p1: (f64, f64),
+        rx: f64,
+        ry: f64,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        p2: (f64, f64),
+    ) -> Option<Self> {
+        let mut rx = rx.abs();
+        let mut ry = ry.abs();
+        if rx == 0.0 || ry == 0.0 || p1 == p2 {
+            return None;
+        }
+
+        let (sin_phi, cos_phi) = x_axis_rotation.to_radians().sin_cos();
+
+        let dx2 = (p1.0 - p2.0) / 2.0;
+        let dy2 = (p1.1 - p2.1) / 2.0;
+        let x1 = cos_phi * dx2 + sin_phi * dy2;
+        let y1 = -sin_phi * dx2 + cos_phi * dy2;
+
+        // Correct out-of-range radii.
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let num = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let den = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let mut coef = (num / den).max(0.0).sqrt();
+        if large_arc == sweep {
+            coef = -coef;
+        }
+
+        let cx1 = coef * rx * y1 / ry;
+        let cy1 = -coef * ry * x1 / rx;
+        let cx = cos_phi * cx1 - sin_phi * cy1 + (p1.0 + p2.0) / 2.0;
+        let cy = sin_phi * cx1 + cos_phi * cy1 + (p1.1 + p2.1) / 2.0;
+
+        let angle = |ux: f64, uy: f64, vx: f64, vy: f64| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+        let theta1 = angle(1.0, 0.0, (x1 - cx1) / rx, (y1 - cy1) / ry);
+        let mut dtheta = angle((x1 - cx1) / rx, (y1 - cy1) / ry, (-x1 - cx1) / rx, (-y1 - cy1) / ry);
+        if !sweep && dtheta > 0.0 {
+            dtheta -= 2.0 * f64::consts::PI;
+        } else if sweep && dtheta < 0.0 {
+            dtheta += 2.0 * f64::consts::PI;
+        }
+
+        Some(CenterArc { cx, cy, rx, ry, sin_phi, cos_phi, theta1, dtheta })
+    }
+
+    // Maps a point on a unit circle onto the ellipse.
+    pub fn map(&self, x: f64, y: f64) -> (f64, f64) {
+        let x = x * self.rx;
+        let y = y * self.ry;
+        (
+            self.cx + self.cos_phi * x - self.sin_phi * y,
+            self.cy + self.sin_phi * x + self.cos_phi * y,
+        )
+    }
+}
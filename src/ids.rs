@@ -0,0 +1,109 @@
+// Elements ids manipulation.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+use crate::{
+    Error,
+    Node,
+};
+
+// Valid first characters of an XML name. Digits are not allowed.
+const FIRST_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Renames all non-empty ids using the provided function.
+///
+/// Nothing will be renamed on error.
+pub fn rename_ids<F>(root: &Node, mut f: F) -> Result<(), Error>
+    where F: FnMut(&str) -> String
+{
+    let nodes: Vec<Node> = root.descendants().filter(|n| n.is_element() && n.has_id()).collect();
+    let new_ids: Vec<String> = nodes.iter().map(|n| f(&n.id())).collect();
+
+    let mut unique = HashSet::new();
+    for (node, id) in nodes.iter().zip(new_ids.iter()) {
+        if id.is_empty() {
+            // Links are serialized using ids.
+            if node.is_used() {
+                return Err(Error::ElementMustHaveAnId);
+            }
+        } else if !unique.insert(id.as_str()) {
+            return Err(Error::DuplicatedId(id.clone()));
+        }
+    }
+
+    for (mut node, id) in nodes.into_iter().zip(new_ids) {
+        node.set_id(id);
+    }
+
+    Ok(())
+}
+
+/// Replaces all non-empty ids with the shortest possible ones.
+pub fn minify_ids(root: &Node) {
+    let mut nodes: Vec<Node> = root.descendants().filter(|n| n.is_element() && n.has_id()).collect();
+
+    // The most used elements will get the shortest ids.
+    // The sort is stable, so the document order is preserved for equally used elements.
+    nodes.sort_by_key(|n| Reverse(n.uses_count()));
+
+    for (i, mut node) in nodes.into_iter().enumerate() {
+        node.set_id(gen_id(i));
+    }
+}
+
+/// Renames all ids that are already used by previous elements.
+pub fn make_ids_unique(root: &Node) -> usize {
+    let nodes: Vec<Node> = root.descendants().filter(|n| n.is_element() && n.has_id()).collect();
+
+    // New ids must not clash with any existing one, even with the following ones.
+    let mut all_ids: HashSet<String> = nodes.iter().map(|n| n.id().clone()).collect();
+    let mut unique = HashSet::new();
+
+    let mut count = 0;
+    for mut node in nodes {
+        let id = node.id().clone();
+        if unique.insert(id.clone()) {
+            continue;
+        }
+
+        let mut idx = 1;
+        let new_id = loop {
+            let new_id = format!("{}-{}", id, idx);
+            if !all_ids.contains(&new_id) {
+                break new_id;
+            }
+
+            idx += 1;
+        };
+
+        all_ids.insert(new_id.clone());
+        unique.insert(new_id.clone());
+        node.set_id(new_id);
+        count += 1;
+    }
+
+    count
+}
+
+// Generates an id from an index: `a`, `b`, ..., `Z`, `aa`, `ab`, ...
+fn gen_id(mut idx: usize) -> String {
+    let mut len = 1;
+    let mut count = FIRST_CHARS.len();
+    while idx >= count {
+        idx -= count;
+        len += 1;
+        count *= CHARS.len();
+    }
+
+    let mut id = Vec::with_capacity(len);
+    for _ in 1..len {
+        id.push(CHARS[idx % CHARS.len()]);
+        idx /= CHARS.len();
+    }
+    id.push(FIRST_CHARS[idx]);
+    id.reverse();
+
+    String::from_utf8(id).unwrap()
+}
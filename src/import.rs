@@ -0,0 +1,242 @@
+// Copying of nodes with their ids and links.
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use log::warn;
+
+use crate::{
+    Attribute,
+    AttributeValue,
+    Document,
+    ElementId,
+    Node,
+    NodeType,
+};
+use crate::link_graph::is_dependency;
+
+/// Copies nodes from another document.
+///
+/// Keeps the mapping between the source and the copied nodes,
+/// so elements shared by multiple imports are copied only once.
+pub struct Importer {
+    // Storage keys of the source nodes to the copied nodes.
+    mapping: HashMap<usize, Node>,
+    // Link attributes of the copied nodes. Still pointing to the source nodes.
+    links: Vec<(Node, Attribute)>,
+    // Ids assigned during the copying.
+    ids: HashSet<String>,
+    // Copy ids as is.
+    keep_ids: bool,
+    // Check copied links for cycles.
+    check_links: bool,
+}
+
+/// Copies a node from another document.
+///
+/// Elements referenced from the copied nodes are imported into the `defs` element.
+pub fn import_node(doc: &mut Document, node: &Node, deep: bool) -> Node {
+    let mut importer = Importer::new();
+    let new_node = importer.copy(doc, node, deep);
+    importer.resolve_links(doc, true);
+    new_node
+}
+
+/// Copies a node with all its children within the same document.
+///
+/// Links to the copied nodes are pointing to the copies.
+pub fn copy_node_deep_with_ids(doc: &mut Document, node: &Node) -> Node {
+    let mut importer = Importer::new();
+    // The original nodes can be detached, so they are not present in the ids index.
+    importer.ids = node.descendants().filter(|n| n.has_id()).map(|n| n.id().clone()).collect();
+    let new_node = importer.copy(doc, node, true);
+    importer.resolve_links(doc, false);
+    new_node
+}
+
+/// Copies all nodes into an empty document.
+///
+/// Detached nodes are copied too, because they can be linked.
+pub fn clone_document(doc: &mut Document, root: &Node, detached: &[Node]) {
+    let mut importer = Importer::new();
+    importer.keep_ids = true;
+    // The links graph of the source document was already validated.
+    importer.check_links = false;
+    importer.mapping.insert(storage_key(root), doc.root());
+
+    for child in root.children() {
+        let new_child = importer.copy(doc, &child, true);
+        doc.root().append(new_child);
+    }
+
+    for node in detached {
+        importer.copy(doc, node, true);
+    }
+
+    importer.resolve_links(doc, false);
+}
+
+/// Returns the first `defs` element of the root `svg` element or creates a new one.
+pub fn defs_element(doc: &mut Document) -> Option<Node> {
+    let mut svg = doc.svg_element()?;
+    if let Some(defs) = svg.children().find(|n| n.has_tag_name(ElementId::Defs)) {
+        return Some(defs);
+    }
+
+    let defs = doc.create_element(ElementId::Defs);
+    svg.prepend(defs.clone());
+    Some(defs)
+}
+
+impl Importer {
+    pub fn new() -> Self {
+        Importer {
+            mapping: HashMap::new(),
+            links: Vec::new(),
+            ids: HashSet::new(),
+            keep_ids: false,
+            check_links: true,
+        }
+    }
+
+    // Links are resolved after the copying, because they can reference
+    // following nodes. Imported elements can add new links, so the list can grow.
+    //
+    // Links to not copied elements are pointing to the original elements,
+    // unless they are from another document and have to be imported.
+    fn resolve_links(&mut self, doc: &mut Document, import: bool) {
+        let mut idx = 0;
+        while idx < self.links.len() {
+            let (mut new_node, attr) = self.links[idx].clone();
+            idx += 1;
+
+            let link = match attr.value {
+                  AttributeValue::Link(ref link)
+                | AttributeValue::FuncLink(ref link)
+                | AttributeValue::Paint(ref link, _) => link.clone(),
+                _ => continue,
+            };
+
+            let target = match self.mapping.get(&storage_key(&link)) {
+                Some(target) => target.clone(),
+                None if !import => link,
+                None => {
+                    // Hyperlinks are not required for rendering,
+                    // so we don't have to import the whole linked content.
+                    if !is_dependency(&new_node, attr.name.as_ref()) {
+                        warn!("Hyperlinks to not imported elements are not supported. \
+                               Attribute {} ignored.", attr.name);
+                        continue;
+                    }
+
+                    let mut defs = match defs_element(doc) {
+                        Some(defs) => defs,
+                        None => {
+                            warn!("The document doesn't have an 'svg' element. \
+                                   Attribute {} ignored.", attr.name);
+                            continue;
+                        }
+                    };
+
+                    let target = self.copy(doc, &link, true);
+                    defs.append(target.clone());
+                    target
+                }
+            };
+
+            let value = match attr.value {
+                AttributeValue::Paint(_, fallback) => AttributeValue::Paint(target, fallback),
+                AttributeValue::FuncLink(_) => AttributeValue::FuncLink(target),
+                _ => AttributeValue::Link(target),
+            };
+
+            let new_attr = Attribute { name: attr.name.clone(), value };
+            if !self.check_links {
+                new_node.set_attribute_unchecked(new_attr);
+            } else if new_node.set_attribute_checked(new_attr).is_err() {
+                warn!("Failed to link a copied element. Attribute {} ignored.", attr.name);
+            }
+        }
+
+        self.links.clear();
+    }
+
+    /// Copies an element with all its children into the `defs` element.
+    ///
+    /// Returns an existing copy if the element was already imported.
+    pub fn import_element(&mut self, doc: &mut Document, node: &Node) -> Option<Node> {
+        if let Some(new_node) = self.mapping.get(&storage_key(node)) {
+            return Some(new_node.clone());
+        }
+
+        let mut defs = defs_element(doc)?;
+        let new_node = self.copy(doc, node, true);
+        defs.append(new_node.clone());
+        self.resolve_links(doc, true);
+        Some(new_node)
+    }
+
+    fn copy(&mut self, doc: &mut Document, node: &Node, deep: bool) -> Node {
+        let mut new_node = match node.node_type() {
+            NodeType::Element => {
+                let mut elem = doc.create_element(node.tag_name().as_ref());
+                for attr in node.attributes().iter() {
+                    if attr.is_link_container() {
+                        self.links.push((elem.clone(), attr.clone()));
+                    } else {
+                        elem.set_attribute(attr.clone());
+                    }
+                }
+
+                if node.has_id() {
+                    let id = if self.keep_ids {
+                        node.id().clone()
+                    } else {
+                        self.unique_id(doc, &node.id())
+                    };
+                    elem.set_id(id);
+                }
+
+                elem
+            }
+            _ => {
+                doc.create_node(node.node_type(), node.text().clone())
+            }
+        };
+
+        self.mapping.entry(storage_key(node)).or_insert_with(|| new_node.clone());
+
+        if deep {
+            for child in node.children() {
+                let new_child = self.copy(doc, &child, true);
+                new_node.append(new_child);
+            }
+        }
+
+        new_node
+    }
+
+    // Renames an id if it's already used by the document or by the copied nodes.
+    fn unique_id(&mut self, doc: &Document, id: &str) -> String {
+        let is_used = |ids: &HashSet<String>, id: &str| {
+            ids.contains(id) || doc.node_by_id(id).is_some()
+        };
+
+        let mut new_id = id.to_string();
+        let mut idx = 1;
+        while is_used(&self.ids, &new_id) {
+            new_id = format!("{}-{}", id, idx);
+            idx += 1;
+        }
+
+        self.ids.insert(new_id.clone());
+        new_id
+    }
+}
+
+// All document nodes have a storage key.
+fn storage_key(node: &Node) -> usize {
+    node.borrow().storage_key.unwrap()
+}
@@ -0,0 +1,336 @@
+//! Typed values of the keyword-based presentation attributes.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    ValueWriteOptions,
+    WriteBuffer,
+};
+
+macro_rules! keyword_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $text:expr),+ $(,)*
+        }
+    ) => (
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        #[allow(missing_docs)]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl $name {
+            /// Returns an original string.
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    $($name::$variant => $text),+
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = svgtypes::Error;
+
+            /// Parses a keyword from a string.
+            ///
+            /// Keywords are case-sensitive.
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                match text {
+                    $($text => Ok($name::$variant),)+
+                    _ => Err(svgtypes::Error::InvalidValue),
+                }
+            }
+        }
+
+        impl WriteBuffer for $name {
+            fn write_buf_opt(&self, _: &ValueWriteOptions, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(self.as_str().as_bytes());
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+    )
+}
+
+keyword_enum! {
+    /// A [`stroke-linecap`](https://www.w3.org/TR/SVG11/painting.html#StrokeLinecapProperty) value.
+    pub enum LineCap {
+        Butt => "butt",
+        Round => "round",
+        Square => "square",
+    }
+}
+
+keyword_enum! {
+    /// A [`stroke-linejoin`](https://www.w3.org/TR/SVG11/painting.html#StrokeLinejoinProperty) value.
+    pub enum LineJoin {
+        Miter => "miter",
+        Round => "round",
+        Bevel => "bevel",
+    }
+}
+
+keyword_enum! {
+    /// A [`fill-rule`](https://www.w3.org/TR/SVG11/painting.html#FillRuleProperty)
+    /// and [`clip-rule`](https://www.w3.org/TR/SVG11/masking.html#ClipRuleProperty) value.
+    pub enum FillRule {
+        NonZero => "nonzero",
+        EvenOdd => "evenodd",
+    }
+}
+
+keyword_enum! {
+    /// A [`display`](https://www.w3.org/TR/SVG11/painting.html#DisplayProperty) value.
+    pub enum DisplayMode {
+        Inline => "inline",
+        Block => "block",
+        ListItem => "list-item",
+        RunIn => "run-in",
+        Compact => "compact",
+        Marker => "marker",
+        Table => "table",
+        InlineTable => "inline-table",
+        TableRowGroup => "table-row-group",
+        TableHeaderGroup => "table-header-group",
+        TableFooterGroup => "table-footer-group",
+        TableRow => "table-row",
+        TableColumnGroup => "table-column-group",
+        TableColumn => "table-column",
+        TableCell => "table-cell",
+        TableCaption => "table-caption",
+        None => "none",
+    }
+}
+
+keyword_enum! {
+    /// A [`visibility`](https://www.w3.org/TR/SVG11/painting.html#VisibilityProperty) value.
+    pub enum Visibility {
+        Visible => "visible",
+        Hidden => "hidden",
+        Collapse => "collapse",
+    }
+}
+
+keyword_enum! {
+    /// A [`text-anchor`](https://www.w3.org/TR/SVG11/text.html#TextAnchorProperty) value.
+    pub enum TextAnchor {
+        Start => "start",
+        Middle => "middle",
+        End => "end",
+    }
+}
+
+keyword_enum! {
+    /// A [`font-weight`](https://www.w3.org/TR/SVG11/text.html#FontWeightProperty) value.
+    pub enum FontWeight {
+        Normal => "normal",
+        Bold => "bold",
+        Bolder => "bolder",
+        Lighter => "lighter",
+        W100 => "100",
+        W200 => "200",
+        W300 => "300",
+        W400 => "400",
+        W500 => "500",
+        W600 => "600",
+        W700 => "700",
+        W800 => "800",
+        W900 => "900",
+    }
+}
+
+keyword_enum! {
+    /// A [`font-style`](https://www.w3.org/TR/SVG11/text.html#FontStyleProperty) value.
+    pub enum FontStyle {
+        Normal => "normal",
+        Italic => "italic",
+        Oblique => "oblique",
+    }
+}
+
+keyword_enum! {
+    /// A [`font-variant`](https://www.w3.org/TR/SVG11/text.html#FontVariantProperty) value.
+    pub enum FontVariant {
+        Normal => "normal",
+        SmallCaps => "small-caps",
+    }
+}
+
+keyword_enum! {
+    /// A [`font-stretch`](https://www.w3.org/TR/SVG11/text.html#FontStretchProperty) value.
+    pub enum FontStretch {
+        Normal => "normal",
+        Wider => "wider",
+        Narrower => "narrower",
+        UltraCondensed => "ultra-condensed",
+        ExtraCondensed => "extra-condensed",
+        Condensed => "condensed",
+        SemiCondensed => "semi-condensed",
+        SemiExpanded => "semi-expanded",
+        Expanded => "expanded",
+        ExtraExpanded => "extra-expanded",
+        UltraExpanded => "ultra-expanded",
+    }
+}
+
+keyword_enum! {
+    /// A [`mix-blend-mode`](https://www.w3.org/TR/compositing-1/#mix-blend-mode) value.
+    pub enum BlendMode {
+        Normal => "normal",
+        Multiply => "multiply",
+        Screen => "screen",
+        Overlay => "overlay",
+        Darken => "darken",
+        Lighten => "lighten",
+        ColorDodge => "color-dodge",
+        ColorBurn => "color-burn",
+        HardLight => "hard-light",
+        SoftLight => "soft-light",
+        Difference => "difference",
+        Exclusion => "exclusion",
+        Hue => "hue",
+        Saturation => "saturation",
+        Color => "color",
+        Luminosity => "luminosity",
+    }
+}
+
+keyword_enum! {
+    /// An [`isolation`](https://www.w3.org/TR/compositing-1/#isolation) value.
+    pub enum Isolation {
+        Auto => "auto",
+        Isolate => "isolate",
+    }
+}
+
+keyword_enum! {
+    /// A [`vector-effect`](https://www.w3.org/TR/SVG2/coords.html#VectorEffects) value.
+    pub enum VectorEffect {
+        None => "none",
+        NonScalingStroke => "non-scaling-stroke",
+        NonScalingSize => "non-scaling-size",
+        NonRotation => "non-rotation",
+        FixedPosition => "fixed-position",
+    }
+}
+
+keyword_enum! {
+    /// A single component of the `paint-order` value.
+    pub enum PaintOrderKind {
+        Fill => "fill",
+        Stroke => "stroke",
+        Markers => "markers",
+    }
+}
+
+/// A [`paint-order`](https://www.w3.org/TR/SVG2/painting.html#PaintOrder) value.
+///
+/// Always contains all three components in the painting order.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PaintOrder {
+    /// Components in the painting order.
+    pub order: [PaintOrderKind; 3],
+}
+
+impl Default for PaintOrder {
+    fn default() -> Self {
+        PaintOrder {
+            order: [PaintOrderKind::Fill, PaintOrderKind::Stroke, PaintOrderKind::Markers],
+        }
+    }
+}
+
+impl PaintOrder {
+    /// Constructs a `PaintOrder` from a list of explicitly specified components.
+    ///
+    /// Missing components are appended in the default order.
+    /// Returns `None` if the list has duplicates.
+    pub fn from_components(list: &[PaintOrderKind]) -> Option<PaintOrder> {
+        if list.len() > 3 {
+            return None;
+        }
+
+        let mut order = Vec::with_capacity(3);
+        for kind in list {
+            if order.contains(kind) {
+                return None;
+            }
+
+            order.push(*kind);
+        }
+
+        for kind in &PaintOrder::default().order {
+            if !order.contains(kind) {
+                order.push(*kind);
+            }
+        }
+
+        Some(PaintOrder { order: [order[0], order[1], order[2]] })
+    }
+
+    /// Returns `true` if the painting order is `fill stroke markers`.
+    pub fn is_default(&self) -> bool {
+        *self == PaintOrder::default()
+    }
+}
+
+impl FromStr for PaintOrder {
+    type Err = svgtypes::Error;
+
+    /// Parses a `paint-order` value.
+    ///
+    /// `normal` or a whitespace-separated list of unique `fill`, `stroke` and `markers`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        if text == "normal" {
+            return Ok(PaintOrder::default());
+        }
+
+        let mut list = Vec::with_capacity(3);
+        for name in text.split_whitespace() {
+            list.push(PaintOrderKind::from_str(name)?);
+        }
+
+        if list.is_empty() {
+            return Err(svgtypes::Error::InvalidValue);
+        }
+
+        PaintOrder::from_components(&list).ok_or(svgtypes::Error::InvalidValue)
+    }
+}
+
+impl WriteBuffer for PaintOrder {
+    fn write_buf_opt(&self, _: &ValueWriteOptions, buf: &mut Vec<u8>) {
+        if self.is_default() {
+            buf.extend_from_slice(b"normal");
+            return;
+        }
+
+        // Write only the components that can't be restored implicitly.
+        let mut len = 1;
+        while PaintOrder::from_components(&self.order[0..len]) != Some(*self) {
+            len += 1;
+        }
+
+        for (i, kind) in self.order[0..len].iter().enumerate() {
+            if i != 0 {
+                buf.push(b' ');
+            }
+
+            buf.extend_from_slice(kind.as_str().as_bytes());
+        }
+    }
+}
+
+impl fmt::Display for PaintOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.with_write_opt(&ValueWriteOptions::default()))
+    }
+}
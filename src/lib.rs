@@ -79,7 +79,7 @@ doc for details.
 - Only most popular attributes are parsed, other stored as strings.
 - No compressed SVG (.svgz). You should decompress it by yourself.
 - CSS support is minimal.
-- SVG 1.1 Full only (no 2.0 Draft, Basic, Tiny subsets).
+- SVG 1.1 Full and a subset of SVG 2 (no Basic and Tiny subsets).
 
 ## Differences between svgdom and SVG spec
 
@@ -95,6 +95,9 @@ doc for details.
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+use std::cell::RefCell;
+use std::rc::Weak;
+
 
 mod attribute;
 mod document;
@@ -109,21 +112,53 @@ mod writer;
 mod attribute_type;
 mod attribute_value;
 mod attributes;
+mod bbox;
+mod cleanup;
+mod flatten;
+mod geom;
+mod ids;
+mod import;
+mod keywords;
+mod link_graph;
+mod path;
+mod resolve;
+mod shapes;
+mod ungroup;
+mod units;
 
 
 pub use crate::attribute::*;
 pub use crate::attribute_type::AttributeType;
-pub use crate::attribute_value::AttributeValue;
+pub use crate::attribute_value::{
+    AttributeValue,
+    ExternalIri,
+};
 pub use crate::attributes::*;
 pub use crate::document::Document;
 pub use crate::element_type::ElementType;
 pub use crate::error::*;
+pub use crate::geom::Rect;
+pub use crate::keywords::*;
+pub use crate::link_graph::{
+    LinkEdge,
+    LinkGraph,
+};
 pub use crate::name::*;
 pub use crate::names::*;
 pub use crate::node::*;
+pub use crate::parser::{
+    ExternalResolver,
+    ParseOptions,
+};
+pub use crate::path::{
+    NormalizeOptions,
+    PathNormalize,
+};
 pub use crate::tree::iterator::*;
 pub use crate::writer::*;
 
+use crate::document::IdIndex;
+
 pub use svgtypes::{
     Align,
     Angle,
@@ -187,4 +222,5 @@ pub struct NodeData {
     attributes: Attributes,
     linked_nodes: Vec<Node>,
     text: String,
+    id_index: Weak<RefCell<IdIndex>>,
 }
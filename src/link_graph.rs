@@ -0,0 +1,315 @@
+// A graph of elements links.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    AttributeId,
+    AttributeQName,
+    AttributeQNameRef,
+    AttributeValue,
+    BorrowError,
+    ElementId,
+    Node,
+};
+
+/// A link between two nodes.
+#[derive(Clone, Debug)]
+pub struct LinkEdge {
+    /// A node that contains the link attribute.
+    pub source: Node,
+    /// A link attribute name.
+    pub attribute: AttributeQName,
+    /// A referenced node.
+    pub target: Node,
+}
+
+/// A graph of all links in the document.
+///
+/// Can be obtained via [`Document::link_graph()`](struct.Document.html#method.link_graph).
+///
+/// The graph is a snapshot and will not be updated on the document modification.
+pub struct LinkGraph {
+    edges: Vec<LinkEdge>,
+    nodes: Vec<Node>,
+    // A target node index for each edge.
+    targets: Vec<usize>,
+    // Edges indexes for each node.
+    adjacency: Vec<Vec<usize>>,
+    // Storage keys to nodes indexes.
+    keys: HashMap<usize, usize>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    New,
+    InProgress,
+    Done,
+}
+
+impl LinkGraph {
+    pub(crate) fn new(root: &Node) -> Self {
+        let mut edges = Vec::new();
+        for node in root.descendants().filter(|n| n.is_element()) {
+            for attr in node.attributes().iter() {
+                match attr.value {
+                      AttributeValue::Link(ref link)
+                    | AttributeValue::FuncLink(ref link)
+                    | AttributeValue::Paint(ref link, _) => {
+                        edges.push(LinkEdge {
+                            source: node.clone(),
+                            attribute: attr.name.clone(),
+                            target: link.clone(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut graph = LinkGraph {
+            edges: Vec::new(),
+            nodes: Vec::new(),
+            targets: Vec::new(),
+            adjacency: Vec::new(),
+            keys: HashMap::new(),
+        };
+
+        for edge in edges {
+            let source = graph.insert_node(&edge.source);
+            let target = graph.insert_node(&edge.target);
+            graph.adjacency[source].push(graph.edges.len());
+            graph.targets.push(target);
+            graph.edges.push(edge);
+        }
+
+        graph
+    }
+
+    fn insert_node(&mut self, node: &Node) -> usize {
+        if let Some(idx) = self.node_index(node) {
+            return idx;
+        }
+
+        let idx = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.adjacency.push(Vec::new());
+        if let Some(key) = node.borrow().storage_key {
+            self.keys.insert(key, idx);
+        }
+
+        idx
+    }
+
+    fn node_index(&self, node: &Node) -> Option<usize> {
+        match node.borrow().storage_key {
+            Some(key) => self.keys.get(&key).cloned(),
+            None => self.nodes.iter().position(|n| n == node),
+        }
+    }
+
+    /// Returns all links in the document order.
+    pub fn edges(&self) -> &[LinkEdge] {
+        &self.edges
+    }
+
+    /// Returns all nodes that are linking or linked, in the order of appearance.
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Returns links of the specified node.
+    pub fn edges_from(&self, node: &Node) -> Vec<&LinkEdge> {
+        match self.node_index(node) {
+            Some(idx) => self.adjacency[idx].iter().map(|i| &self.edges[*i]).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns nodes ordered so that each node goes after all nodes it links to.
+    ///
+    /// Returns `None` when the graph has cycles.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    ///     <linearGradient id='lg2' xlink:href='#lg1'/>
+    ///     <linearGradient id='lg1'/>
+    /// </svg>").unwrap();
+    ///
+    /// let order = doc.link_graph().topological_order().unwrap();
+    /// assert_eq!(*order[0].id(), "lg1");
+    /// assert_eq!(*order[1].id(), "lg2");
+    /// ```
+    pub fn topological_order(&self) -> Option<Vec<Node>> {
+        let mut states = vec![State::New; self.nodes.len()];
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut path = Vec::new();
+        for idx in 0..self.nodes.len() {
+            if self.visit(idx, &mut states, &mut order, &mut path) {
+                return None;
+            }
+        }
+
+        Some(order.into_iter().map(|idx| self.nodes[idx].clone()).collect())
+    }
+
+    /// Returns `true` if the graph has cycles.
+    ///
+    /// [`Node::set_attribute_checked()`](struct.Node.html#method.set_attribute_checked)
+    /// doesn't allow cycles of rendering dependencies, so only hyperlinks
+    /// can form a cycle.
+    pub fn has_cycles(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Returns links that are forming a cycle, if any.
+    ///
+    /// The target of the last link is the source of the first one.
+    pub fn find_cycle(&self) -> Option<Vec<LinkEdge>> {
+        let mut states = vec![State::New; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut path = Vec::new();
+        for idx in 0..self.nodes.len() {
+            if self.visit(idx, &mut states, &mut order, &mut path) {
+                // The path ends with an edge to a node that is already in the path.
+                let last = &self.edges[*path.last().unwrap()];
+                let start = path.iter()
+                    .position(|i| self.edges[*i].source == last.target)
+                    .unwrap_or(0);
+
+                return Some(path[start..].iter().map(|i| self.edges[*i].clone()).collect());
+            }
+        }
+
+        None
+    }
+
+    // A depth-first search.
+    //
+    // Returns `true` when a cycle is found. In this case `path`
+    // contains edges from the start node to the cycle end.
+    fn visit(
+        &self,
+        idx: usize,
+        states: &mut [State],
+        order: &mut Vec<usize>,
+        path: &mut Vec<usize>,
+    ) -> bool {
+        match states[idx] {
+            State::Done => return false,
+            State::InProgress => return true,
+            State::New => {}
+        }
+
+        states[idx] = State::InProgress;
+        for edge_idx in &self.adjacency[idx] {
+            path.push(*edge_idx);
+            if self.visit(self.targets[*edge_idx], states, order, path) {
+                return true;
+            }
+            path.pop();
+        }
+        states[idx] = State::Done;
+        order.push(idx);
+
+        false
+    }
+}
+
+// Hyperlinks don't affect the rendering, so they can't create a cycle.
+pub(crate) fn is_dependency(node: &Node, name: AttributeQNameRef) -> bool {
+    !(node.has_tag_name(ElementId::A) && name.has_id(AttributeId::Href))
+}
+
+// Checks that a link from the `node` to the `target` will create a cycle.
+//
+// An element depends on the elements referenced by it and by its descendants,
+// so a cycle is created when the `node` or one of its ancestors is reachable from the `target`.
+//
+// Instead of walking the `target` subtree and its links, we are going backward:
+// from the `node` to its ancestors and from them to the elements that are referencing them.
+// The `target` is reachable only when it's one of the ancestors found this way.
+// This way only the nodes that are already depending on the `node` are visited.
+//
+// All the visited nodes are borrowed, so a borrowing error is returned instead of panicking.
+pub(crate) fn is_cyclic_link(node: &Node, target: &Node) -> Result<bool, BorrowError> {
+    let mut visited = NodeSet::new();
+    let mut stack = vec![node.clone()];
+    while let Some(n) = stack.pop() {
+        let mut parent = Some(n);
+        while let Some(n) = parent {
+            if n == *target {
+                return Ok(true);
+            }
+
+            // Ancestors of a visited node are visited too.
+            if !visited.insert(&n)? {
+                break;
+            }
+
+            for source in n.try_linked_nodes()?.iter() {
+                if is_dependency_link(source, &n)? {
+                    stack.push(source.clone());
+                }
+            }
+
+            parent = n.try_parent()?;
+        }
+    }
+
+    Ok(false)
+}
+
+// Checks that the `source` has a dependency link to the `target`.
+fn is_dependency_link(source: &Node, target: &Node) -> Result<bool, BorrowError> {
+    let is_a = source.try_tag_name()?.has_id(ElementId::A);
+    for attr in source.try_attributes()?.iter() {
+        match attr.value {
+              AttributeValue::Link(ref link)
+            | AttributeValue::FuncLink(ref link)
+            | AttributeValue::Paint(ref link, _)
+            if *link == *target && !(is_a && attr.name.has_id(AttributeId::Href)) => {
+                return Ok(true);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(false)
+}
+
+// A set of nodes.
+//
+// Nodes that belong to a document are compared by the storage key.
+struct NodeSet {
+    keys: HashSet<usize>,
+    nodes: Vec<Node>,
+}
+
+impl NodeSet {
+    fn new() -> Self {
+        NodeSet {
+            keys: HashSet::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    // Returns `false` if the set already contains the node.
+    fn insert(&mut self, node: &Node) -> Result<bool, BorrowError> {
+        let key = node.try_borrow()?.storage_key;
+        match key {
+            Some(key) => Ok(self.keys.insert(key)),
+            None => {
+                if self.nodes.contains(node) {
+                    Ok(false)
+                } else {
+                    self.nodes.push(node.clone());
+                    Ok(true)
+                }
+            }
+        }
+    }
+}
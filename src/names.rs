@@ -57,12 +57,18 @@ pub enum ElementId {
     G,
     Glyph,
     GlyphRef,
+    Hatch,
+    Hatchpath,
     Hkern,
     Image,
     Line,
     LinearGradient,
     Marker,
     Mask,
+    Mesh,
+    Meshgradient,
+    Meshpatch,
+    Meshrow,
     Metadata,
     MissingGlyph,
     Mpath,
@@ -74,6 +80,7 @@ pub enum ElementId {
     Rect,
     Script,
     Set,
+    Solidcolor,
     Stop,
     Style,
     Svg,
@@ -92,104 +99,113 @@ pub enum ElementId {
 static ELEMENTS: Map<ElementId> = Map {
     key: 732231254413039614,
     disps: &[
-        (5, 43),
-        (0, 2),
-        (0, 41),
+        (1, 67),
+        (1, 58),
+        (0, 26),
         (0, 0),
-        (0, 16),
-        (11, 5),
-        (0, 12),
-        (4, 18),
-        (22, 32),
-        (5, 9),
-        (2, 56),
-        (6, 13),
-        (2, 24),
-        (0, 21),
-        (9, 52),
-        (0, 3),
+        (0, 54),
+        (1, 71),
+        (13, 61),
+        (4, 32),
+        (0, 2),
+        (0, 5),
+        (15, 18),
+        (24, 54),
+        (4, 31),
+        (0, 65),
+        (7, 57),
+        (10, 35),
+        (0, 23),
+        (0, 42),
     ],
     entries: &[
-        ("font-face-uri", ElementId::FontFaceUri),
-        ("feFuncR", ElementId::FeFuncR),
+        ("rect", ElementId::Rect),
+        ("feSpecularLighting", ElementId::FeSpecularLighting),
+        ("solidcolor", ElementId::Solidcolor),
+        ("tspan", ElementId::Tspan),
+        ("feFuncB", ElementId::FeFuncB),
+        ("feDiffuseLighting", ElementId::FeDiffuseLighting),
+        ("fePointLight", ElementId::FePointLight),
+        ("feMerge", ElementId::FeMerge),
+        ("animate", ElementId::Animate),
         ("text", ElementId::Text),
         ("title", ElementId::Title),
-        ("feBlend", ElementId::FeBlend),
-        ("missing-glyph", ElementId::MissingGlyph),
-        ("animateColor", ElementId::AnimateColor),
-        ("marker", ElementId::Marker),
-        ("feMergeNode", ElementId::FeMergeNode),
+        ("path", ElementId::Path),
+        ("feSpotLight", ElementId::FeSpotLight),
+        ("desc", ElementId::Desc),
         ("filter", ElementId::Filter),
-        ("feOffset", ElementId::FeOffset),
-        ("feSpecularLighting", ElementId::FeSpecularLighting),
-        ("stop", ElementId::Stop),
-        ("clipPath", ElementId::ClipPath),
-        ("line", ElementId::Line),
-        ("ellipse", ElementId::Ellipse),
-        ("radialGradient", ElementId::RadialGradient),
-        ("feConvolveMatrix", ElementId::FeConvolveMatrix),
-        ("feMerge", ElementId::FeMerge),
-        ("feDiffuseLighting", ElementId::FeDiffuseLighting),
-        ("feFuncG", ElementId::FeFuncG),
-        ("feMorphology", ElementId::FeMorphology),
-        ("color-profile", ElementId::ColorProfile),
+        ("font-face-src", ElementId::FontFaceSrc),
+        ("feFuncR", ElementId::FeFuncR),
+        ("cursor", ElementId::Cursor),
+        ("foreignObject", ElementId::ForeignObject),
+        ("font-face", ElementId::FontFace),
+        ("vkern", ElementId::Vkern),
         ("use", ElementId::Use),
-        ("animate", ElementId::Animate),
-        ("polyline", ElementId::Polyline),
-        ("hkern", ElementId::Hkern),
-        ("svg", ElementId::Svg),
+        ("script", ElementId::Script),
+        ("mpath", ElementId::Mpath),
+        ("feImage", ElementId::FeImage),
+        ("feBlend", ElementId::FeBlend),
+        ("font-face-name", ElementId::FontFaceName),
+        ("meshgradient", ElementId::Meshgradient),
         ("feComposite", ElementId::FeComposite),
-        ("rect", ElementId::Rect),
-        ("font-face", ElementId::FontFace),
-        ("a", ElementId::A),
-        ("path", ElementId::Path),
-        ("linearGradient", ElementId::LinearGradient),
+        ("hatch", ElementId::Hatch),
+        ("symbol", ElementId::Symbol),
         ("feComponentTransfer", ElementId::FeComponentTransfer),
-        ("feTile", ElementId::FeTile),
-        ("altGlyphDef", ElementId::AltGlyphDef),
+        ("circle", ElementId::Circle),
+        ("g", ElementId::G),
         ("feFuncA", ElementId::FeFuncA),
-        ("symbol", ElementId::Symbol),
-        ("glyphRef", ElementId::GlyphRef),
-        ("glyph", ElementId::Glyph),
-        ("style", ElementId::Style),
-        ("switch", ElementId::Switch),
-        ("set", ElementId::Set),
-        ("font-face-name", ElementId::FontFaceName),
-        ("font-face-src", ElementId::FontFaceSrc),
-        ("font-face-format", ElementId::FontFaceFormat),
-        ("feFlood", ElementId::FeFlood),
-        ("tref", ElementId::Tref),
-        ("feFuncB", ElementId::FeFuncB),
-        ("mpath", ElementId::Mpath),
-        ("animateMotion", ElementId::AnimateMotion),
         ("defs", ElementId::Defs),
-        ("feGaussianBlur", ElementId::FeGaussianBlur),
+        ("font-face-format", ElementId::FontFaceFormat),
+        ("feMorphology", ElementId::FeMorphology),
+        ("feConvolveMatrix", ElementId::FeConvolveMatrix),
+        ("hatchpath", ElementId::Hatchpath),
+        ("feDistantLight", ElementId::FeDistantLight),
+        ("feColorMatrix", ElementId::FeColorMatrix),
         ("view", ElementId::View),
-        ("foreignObject", ElementId::ForeignObject),
-        ("tspan", ElementId::Tspan),
-        ("altGlyph", ElementId::AltGlyph),
-        ("cursor", ElementId::Cursor),
-        ("feDisplacementMap", ElementId::FeDisplacementMap),
+        ("feFlood", ElementId::FeFlood),
+        ("image", ElementId::Image),
         ("pattern", ElementId::Pattern),
+        ("line", ElementId::Line),
+        ("marker", ElementId::Marker),
+        ("textPath", ElementId::TextPath),
+        ("metadata", ElementId::Metadata),
+        ("style", ElementId::Style),
+        ("svg", ElementId::Svg),
+        ("stop", ElementId::Stop),
         ("mask", ElementId::Mask),
+        ("feMergeNode", ElementId::FeMergeNode),
+        ("set", ElementId::Set),
+        ("color-profile", ElementId::ColorProfile),
+        ("feGaussianBlur", ElementId::FeGaussianBlur),
+        ("meshrow", ElementId::Meshrow),
         ("font", ElementId::Font),
-        ("desc", ElementId::Desc),
-        ("feImage", ElementId::FeImage),
-        ("feColorMatrix", ElementId::FeColorMatrix),
+        ("clipPath", ElementId::ClipPath),
+        ("meshpatch", ElementId::Meshpatch),
+        ("a", ElementId::A),
+        ("altGlyph", ElementId::AltGlyph),
+        ("glyphRef", ElementId::GlyphRef),
+        ("mesh", ElementId::Mesh),
+        ("feFuncG", ElementId::FeFuncG),
+        ("missing-glyph", ElementId::MissingGlyph),
+        ("switch", ElementId::Switch),
+        ("animateColor", ElementId::AnimateColor),
+        ("feDisplacementMap", ElementId::FeDisplacementMap),
+        ("radialGradient", ElementId::RadialGradient),
+        ("hkern", ElementId::Hkern),
+        ("glyph", ElementId::Glyph),
         ("feTurbulence", ElementId::FeTurbulence),
-        ("feDistantLight", ElementId::FeDistantLight),
-        ("g", ElementId::G),
-        ("fePointLight", ElementId::FePointLight),
-        ("feSpotLight", ElementId::FeSpotLight),
-        ("script", ElementId::Script),
-        ("image", ElementId::Image),
-        ("metadata", ElementId::Metadata),
-        ("vkern", ElementId::Vkern),
-        ("textPath", ElementId::TextPath),
+        ("tref", ElementId::Tref),
+        ("ellipse", ElementId::Ellipse),
+        ("feTile", ElementId::FeTile),
+        ("animateTransform", ElementId::AnimateTransform),
         ("polygon", ElementId::Polygon),
-        ("circle", ElementId::Circle),
+        ("polyline", ElementId::Polyline),
         ("altGlyphItem", ElementId::AltGlyphItem),
-        ("animateTransform", ElementId::AnimateTransform),
+        ("feOffset", ElementId::FeOffset),
+        ("linearGradient", ElementId::LinearGradient),
+        ("altGlyphDef", ElementId::AltGlyphDef),
+        ("font-face-uri", ElementId::FontFaceUri),
+        ("animateMotion", ElementId::AnimateMotion),
     ],
 };
 
@@ -293,6 +309,7 @@ pub enum AttributeId {
     FontVariant,
     FontWeight,
     Format,
+    Fr,
     From,
     Fx,
     Fy,
@@ -305,6 +322,8 @@ pub enum AttributeId {
     GradientTransform,
     GradientUnits,
     Hanging,
+    HatchContentUnits,
+    HatchUnits,
     Height,
     HorizAdvX,
     HorizOriginX,
@@ -316,6 +335,7 @@ pub enum AttributeId {
     In,
     In2,
     Intercept,
+    Isolation,
     K,
     K1,
     K2,
@@ -349,6 +369,7 @@ pub enum AttributeId {
     Media,
     Method,
     Min,
+    MixBlendMode,
     Mode,
     Name,
     NumOctaves,
@@ -381,12 +402,14 @@ pub enum AttributeId {
     Overflow,
     OverlinePosition,
     OverlineThickness,
+    PaintOrder,
     Panose1,
     Path,
     PathLength,
     PatternContentUnits,
     PatternTransform,
     PatternUnits,
+    Pitch,
     PointerEvents,
     Points,
     PointsAtX,
@@ -415,6 +438,8 @@ pub enum AttributeId {
     ShapeRendering,
     Show,
     Slope,
+    SolidColor,
+    SolidOpacity,
     Space,
     Spacing,
     SpecularConstant,
@@ -453,6 +478,7 @@ pub enum AttributeId {
     Title,
     To,
     Transform,
+    TransformOrigin,
     Type,
     U1,
     U2,
@@ -467,6 +493,7 @@ pub enum AttributeId {
     VIdeographic,
     VMathematical,
     Values,
+    VectorEffect,
     Version,
     VertAdvY,
     VertOriginX,
@@ -494,334 +521,348 @@ pub enum AttributeId {
 }
 
 static ATTRIBUTES: Map<AttributeId> = Map {
-    key: 732231254413039614,
+    key: 3213172566270843353,
     disps: &[
-        (0, 1),
-        (1, 123),
-        (0, 115),
-        (0, 2),
+        (0, 0),
+        (0, 4),
+        (0, 24),
+        (1, 19),
+        (0, 18),
+        (0, 180),
+        (0, 0),
+        (0, 52),
+        (0, 35),
+        (0, 52),
+        (1, 140),
+        (1, 125),
         (0, 2),
-        (0, 16),
-        (1, 155),
-        (1, 26),
-        (1, 211),
-        (0, 5),
-        (0, 227),
-        (1, 96),
-        (0, 141),
         (0, 2),
-        (0, 211),
-        (0, 18),
-        (0, 19),
-        (0, 17),
-        (2, 105),
-        (3, 1),
-        (0, 28),
-        (0, 9),
-        (0, 242),
-        (0, 27),
-        (0, 17),
-        (0, 68),
-        (6, 41),
-        (8, 57),
-        (0, 125),
-        (20, 219),
-        (0, 54),
-        (3, 93),
-        (0, 29),
-        (7, 60),
-        (3, 192),
-        (2, 96),
-        (0, 262),
-        (5, 50),
+        (1, 19),
+        (0, 93),
         (0, 3),
-        (0, 111),
-        (0, 39),
-        (0, 17),
-        (0, 1),
-        (0, 217),
+        (0, 233),
+        (0, 7),
         (0, 0),
-        (3, 159),
-        (0, 20),
-        (3, 57),
-        (0, 52),
-        (14, 224),
+        (1, 1),
+        (0, 40),
+        (2, 233),
+        (0, 23),
+        (0, 138),
+        (0, 62),
+        (0, 12),
+        (0, 271),
         (0, 0),
-        (9, 236),
-        (20, 213),
-        (4, 265),
+        (0, 248),
+        (0, 4),
+        (4, 13),
+        (2, 194),
+        (2, 36),
+        (0, 43),
+        (0, 2),
+        (0, 19),
+        (1, 230),
+        (0, 205),
+        (1, 13),
+        (0, 248),
+        (0, 4),
+        (6, 58),
+        (3, 29),
+        (1, 60),
+        (2, 0),
+        (0, 89),
+        (5, 56),
+        (8, 7),
+        (0, 129),
+        (0, 138),
+        (25, 198),
+        (0, 143),
+        (3, 213),
+        (18, 192),
+        (0, 14),
+        (37, 23),
     ],
     entries: &[
-        ("order", AttributeId::Order),
-        ("lengthAdjust", AttributeId::LengthAdjust),
-        ("offset", AttributeId::Offset),
-        ("cy", AttributeId::Cy),
-        ("azimuth", AttributeId::Azimuth),
-        ("viewTarget", AttributeId::ViewTarget),
-        ("systemLanguage", AttributeId::SystemLanguage),
-        ("exponent", AttributeId::Exponent),
-        ("targetX", AttributeId::TargetX),
-        ("specularConstant", AttributeId::SpecularConstant),
-        ("gradientTransform", AttributeId::GradientTransform),
-        ("onclick", AttributeId::Onclick),
-        ("opacity", AttributeId::Opacity),
-        ("string", AttributeId::String),
-        ("onbegin", AttributeId::Onbegin),
-        ("k4", AttributeId::K4),
-        ("clip-path", AttributeId::ClipPath),
-        ("bias", AttributeId::Bias),
-        ("font", AttributeId::Font),
         ("dx", AttributeId::Dx),
-        ("y1", AttributeId::Y1),
-        ("keyTimes", AttributeId::KeyTimes),
-        ("markerUnits", AttributeId::MarkerUnits),
-        ("stdDeviation", AttributeId::StdDeviation),
-        ("baseProfile", AttributeId::BaseProfile),
-        ("class", AttributeId::Class),
-        ("vert-adv-y", AttributeId::VertAdvY),
-        ("widths", AttributeId::Widths),
-        ("vert-origin-x", AttributeId::VertOriginX),
-        ("color-profile", AttributeId::ColorProfile),
-        ("k1", AttributeId::K1),
-        ("path", AttributeId::Path),
-        ("markerWidth", AttributeId::MarkerWidth),
-        ("surfaceScale", AttributeId::SurfaceScale),
-        ("space", AttributeId::Space),
+        ("pathLength", AttributeId::PathLength),
+        ("base", AttributeId::Base),
         ("onmouseup", AttributeId::Onmouseup),
-        ("y", AttributeId::Y),
+        ("v-mathematical", AttributeId::VMathematical),
+        ("media", AttributeId::Media),
         ("text-decoration", AttributeId::TextDecoration),
-        ("patternUnits", AttributeId::PatternUnits),
-        ("lighting-color", AttributeId::LightingColor),
-        ("color-interpolation-filters", AttributeId::ColorInterpolationFilters),
-        ("bbox", AttributeId::Bbox),
-        ("startOffset", AttributeId::StartOffset),
-        ("to", AttributeId::To),
-        ("version", AttributeId::Version),
-        ("targetY", AttributeId::TargetY),
-        ("style", AttributeId::Style),
-        ("method", AttributeId::Method),
-        ("stemh", AttributeId::Stemh),
-        ("x", AttributeId::X),
-        ("externalResourcesRequired", AttributeId::ExternalResourcesRequired),
-        ("k", AttributeId::K),
+        ("x1", AttributeId::X1),
+        ("encoding", AttributeId::Encoding),
+        ("min", AttributeId::Min),
+        ("target", AttributeId::Target),
+        ("shape-rendering", AttributeId::ShapeRendering),
+        ("mix-blend-mode", AttributeId::MixBlendMode),
+        ("pitch", AttributeId::Pitch),
+        ("amplitude", AttributeId::Amplitude),
+        ("additive", AttributeId::Additive),
+        ("surfaceScale", AttributeId::SurfaceScale),
+        ("onunload", AttributeId::Onunload),
+        ("overflow", AttributeId::Overflow),
+        ("fill-opacity", AttributeId::FillOpacity),
+        ("gradientUnits", AttributeId::GradientUnits),
+        ("y2", AttributeId::Y2),
+        ("vert-origin-x", AttributeId::VertOriginX),
+        ("stitchTiles", AttributeId::StitchTiles),
+        ("overline-thickness", AttributeId::OverlineThickness),
+        ("onmouseover", AttributeId::Onmouseover),
+        ("id", AttributeId::Id),
+        ("glyph-name", AttributeId::GlyphName),
+        ("strikethrough-position", AttributeId::StrikethroughPosition),
+        ("glyph-orientation-vertical", AttributeId::GlyphOrientationVertical),
+        ("requiredExtensions", AttributeId::RequiredExtensions),
+        ("color-profile", AttributeId::ColorProfile),
+        ("class", AttributeId::Class),
+        ("image-rendering", AttributeId::ImageRendering),
+        ("viewBox", AttributeId::ViewBox),
+        ("requiredFeatures", AttributeId::RequiredFeatures),
         ("onmousemove", AttributeId::Onmousemove),
-        ("format", AttributeId::Format),
-        ("role", AttributeId::Role),
-        ("specularExponent", AttributeId::SpecularExponent),
+        ("u2", AttributeId::U2),
+        ("horiz-origin-x", AttributeId::HorizOriginX),
+        ("cx", AttributeId::Cx),
         ("patternTransform", AttributeId::PatternTransform),
-        ("points", AttributeId::Points),
-        ("markerHeight", AttributeId::MarkerHeight),
-        ("stitchTiles", AttributeId::StitchTiles),
-        ("fill-rule", AttributeId::FillRule),
-        ("g1", AttributeId::G1),
-        ("unicode", AttributeId::Unicode),
+        ("font-style", AttributeId::FontStyle),
+        ("glyphRef", AttributeId::GlyphRef),
+        ("exponent", AttributeId::Exponent),
+        ("lengthAdjust", AttributeId::LengthAdjust),
+        ("result", AttributeId::Result),
+        ("xmlns", AttributeId::Xmlns),
+        ("lang", AttributeId::Lang),
+        ("alphabetic", AttributeId::Alphabetic),
+        ("stroke-width", AttributeId::StrokeWidth),
+        ("pointsAtZ", AttributeId::PointsAtZ),
+        ("values", AttributeId::Values),
         ("horiz-origin-y", AttributeId::HorizOriginY),
-        ("amplitude", AttributeId::Amplitude),
-        ("baseFrequency", AttributeId::BaseFrequency),
-        ("from", AttributeId::From),
-        ("text-anchor", AttributeId::TextAnchor),
-        ("kernelMatrix", AttributeId::KernelMatrix),
-        ("clipPathUnits", AttributeId::ClipPathUnits),
-        ("panose-1", AttributeId::Panose1),
-        ("pointer-events", AttributeId::PointerEvents),
+        ("version", AttributeId::Version),
         ("radius", AttributeId::Radius),
-        ("local", AttributeId::Local),
-        ("type", AttributeId::Type),
-        ("min", AttributeId::Min),
-        ("id", AttributeId::Id),
-        ("kernelUnitLength", AttributeId::KernelUnitLength),
+        ("orientation", AttributeId::Orientation),
+        ("name", AttributeId::Name),
+        ("onclick", AttributeId::Onclick),
+        ("strikethrough-thickness", AttributeId::StrikethroughThickness),
+        ("stop-opacity", AttributeId::StopOpacity),
+        ("onactivate", AttributeId::Onactivate),
+        ("underline-position", AttributeId::UnderlinePosition),
+        ("y1", AttributeId::Y1),
+        ("intercept", AttributeId::Intercept),
+        ("primitiveUnits", AttributeId::PrimitiveUnits),
+        ("zoomAndPan", AttributeId::ZoomAndPan),
+        ("hatchUnits", AttributeId::HatchUnits),
+        ("refX", AttributeId::RefX),
+        ("paint-order", AttributeId::PaintOrder),
+        ("keyTimes", AttributeId::KeyTimes),
+        ("hatchContentUnits", AttributeId::HatchContentUnits),
+        ("onrepeat", AttributeId::Onrepeat),
+        ("k", AttributeId::K),
+        ("line-height", AttributeId::LineHeight),
+        ("marker-mid", AttributeId::MarkerMid),
+        ("orient", AttributeId::Orient),
+        ("xlink", AttributeId::Xlink),
+        ("vert-adv-y", AttributeId::VertAdvY),
+        ("format", AttributeId::Format),
+        ("startOffset", AttributeId::StartOffset),
+        ("color", AttributeId::Color),
+        ("visibility", AttributeId::Visibility),
+        ("specularConstant", AttributeId::SpecularConstant),
+        ("stroke-dashoffset", AttributeId::StrokeDashoffset),
+        ("overline-position", AttributeId::OverlinePosition),
+        ("stroke-opacity", AttributeId::StrokeOpacity),
+        ("filter", AttributeId::Filter),
+        ("solid-color", AttributeId::SolidColor),
+        ("g1", AttributeId::G1),
         ("descent", AttributeId::Descent),
-        ("direction", AttributeId::Direction),
-        ("encoding", AttributeId::Encoding),
-        ("calcMode", AttributeId::CalcMode),
-        ("attributeType", AttributeId::AttributeType),
+        ("spreadMethod", AttributeId::SpreadMethod),
+        ("unicode-range", AttributeId::UnicodeRange),
+        ("systemLanguage", AttributeId::SystemLanguage),
+        ("onend", AttributeId::Onend),
         ("onfocusin", AttributeId::Onfocusin),
-        ("values", AttributeId::Values),
-        ("baseline-shift", AttributeId::BaselineShift),
-        ("u2", AttributeId::U2),
         ("letter-spacing", AttributeId::LetterSpacing),
-        ("fill-opacity", AttributeId::FillOpacity),
-        ("image-rendering", AttributeId::ImageRendering),
-        ("flood-opacity", AttributeId::FloodOpacity),
-        ("xmlns", AttributeId::Xmlns),
-        ("spacing", AttributeId::Spacing),
-        ("rx", AttributeId::Rx),
-        ("repeatCount", AttributeId::RepeatCount),
-        ("horiz-origin-x", AttributeId::HorizOriginX),
-        ("stroke", AttributeId::Stroke),
+        ("specularExponent", AttributeId::SpecularExponent),
         ("ry", AttributeId::Ry),
-        ("href", AttributeId::Href),
-        ("maskUnits", AttributeId::MaskUnits),
-        ("font-family", AttributeId::FontFamily),
-        ("onmousedown", AttributeId::Onmousedown),
-        ("xChannelSelector", AttributeId::XChannelSelector),
+        ("string", AttributeId::String),
+        ("font-size", AttributeId::FontSize),
+        ("stemv", AttributeId::Stemv),
+        ("actuate", AttributeId::Actuate),
+        ("seed", AttributeId::Seed),
+        ("onmouseout", AttributeId::Onmouseout),
+        ("kernelUnitLength", AttributeId::KernelUnitLength),
+        ("markerUnits", AttributeId::MarkerUnits),
+        ("pointer-events", AttributeId::PointerEvents),
+        ("flood-color", AttributeId::FloodColor),
+        ("path", AttributeId::Path),
+        ("standalone", AttributeId::Standalone),
+        ("refY", AttributeId::RefY),
+        ("onresize", AttributeId::Onresize),
         ("height", AttributeId::Height),
+        ("enable-background", AttributeId::EnableBackground),
+        ("slope", AttributeId::Slope),
+        ("stroke-miterlimit", AttributeId::StrokeMiterlimit),
+        ("color-interpolation-filters", AttributeId::ColorInterpolationFilters),
+        ("dy", AttributeId::Dy),
+        ("flood-opacity", AttributeId::FloodOpacity),
+        ("cursor", AttributeId::Cursor),
+        ("stemh", AttributeId::Stemh),
+        ("hanging", AttributeId::Hanging),
+        ("accumulate", AttributeId::Accumulate),
+        ("targetY", AttributeId::TargetY),
+        ("v-ideographic", AttributeId::VIdeographic),
+        ("in2", AttributeId::In2),
+        ("font-family", AttributeId::FontFamily),
+        ("fy", AttributeId::Fy),
+        ("baseline-shift", AttributeId::BaselineShift),
+        ("font-size-adjust", AttributeId::FontSizeAdjust),
+        ("units-per-em", AttributeId::UnitsPerEm),
+        ("text-anchor", AttributeId::TextAnchor),
+        ("onzoom", AttributeId::Onzoom),
+        ("begin", AttributeId::Begin),
+        ("k4", AttributeId::K4),
+        ("text-rendering", AttributeId::TextRendering),
+        ("color-interpolation", AttributeId::ColorInterpolation),
+        ("font-variant", AttributeId::FontVariant),
+        ("panose-1", AttributeId::Panose1),
         ("v-hanging", AttributeId::VHanging),
         ("arabic-form", AttributeId::ArabicForm),
-        ("max", AttributeId::Max),
-        ("target", AttributeId::Target),
-        ("filterUnits", AttributeId::FilterUnits),
-        ("scale", AttributeId::Scale),
-        ("clip", AttributeId::Clip),
-        ("onmouseover", AttributeId::Onmouseover),
-        ("show", AttributeId::Show),
-        ("refX", AttributeId::RefX),
-        ("overline-position", AttributeId::OverlinePosition),
-        ("origin", AttributeId::Origin),
-        ("arcrole", AttributeId::Arcrole),
-        ("stroke-opacity", AttributeId::StrokeOpacity),
-        ("filterRes", AttributeId::FilterRes),
+        ("operator", AttributeId::Operator),
+        ("maskUnits", AttributeId::MaskUnits),
+        ("opacity", AttributeId::Opacity),
+        ("rendering-intent", AttributeId::RenderingIntent),
+        ("calcMode", AttributeId::CalcMode),
+        ("unicode", AttributeId::Unicode),
+        ("spacing", AttributeId::Spacing),
+        ("in", AttributeId::In),
+        ("onload", AttributeId::Onload),
+        ("attributeName", AttributeId::AttributeName),
+        ("contentStyleType", AttributeId::ContentStyleType),
+        ("width", AttributeId::Width),
+        ("x", AttributeId::X),
+        ("keySplines", AttributeId::KeySplines),
+        ("rotate", AttributeId::Rotate),
+        ("marker-end", AttributeId::MarkerEnd),
         ("contentScriptType", AttributeId::ContentScriptType),
-        ("seed", AttributeId::Seed),
-        ("onactivate", AttributeId::Onactivate),
-        ("shape-rendering", AttributeId::ShapeRendering),
-        ("v-mathematical", AttributeId::VMathematical),
+        ("numOctaves", AttributeId::NumOctaves),
+        ("onmousedown", AttributeId::Onmousedown),
+        ("clipPathUnits", AttributeId::ClipPathUnits),
         ("vert-origin-y", AttributeId::VertOriginY),
-        ("pointsAtX", AttributeId::PointsAtX),
-        ("stroke-width", AttributeId::StrokeWidth),
-        ("dominant-baseline", AttributeId::DominantBaseline),
+        ("fx", AttributeId::Fx),
+        ("attributeType", AttributeId::AttributeType),
+        ("externalResourcesRequired", AttributeId::ExternalResourcesRequired),
+        ("onerror", AttributeId::Onerror),
+        ("mode", AttributeId::Mode),
+        ("xChannelSelector", AttributeId::XChannelSelector),
+        ("max", AttributeId::Max),
+        ("yChannelSelector", AttributeId::YChannelSelector),
+        ("g2", AttributeId::G2),
+        ("space", AttributeId::Space),
+        ("type", AttributeId::Type),
+        ("baseFrequency", AttributeId::BaseFrequency),
+        ("edgeMode", AttributeId::EdgeMode),
+        ("href", AttributeId::Href),
+        ("widths", AttributeId::Widths),
+        ("repeatDur", AttributeId::RepeatDur),
+        ("bias", AttributeId::Bias),
+        ("k2", AttributeId::K2),
+        ("lighting-color", AttributeId::LightingColor),
+        ("unicode-bidi", AttributeId::UnicodeBidi),
+        ("direction", AttributeId::Direction),
+        ("underline-thickness", AttributeId::UnderlineThickness),
+        ("fill-rule", AttributeId::FillRule),
+        ("markerHeight", AttributeId::MarkerHeight),
+        ("mask", AttributeId::Mask),
         ("divisor", AttributeId::Divisor),
-        ("in2", AttributeId::In2),
-        ("onresize", AttributeId::Onresize),
-        ("width", AttributeId::Width),
-        ("y2", AttributeId::Y2),
-        ("strikethrough-position", AttributeId::StrikethroughPosition),
-        ("font-size-adjust", AttributeId::FontSizeAdjust),
-        ("glyph-orientation-vertical", AttributeId::GlyphOrientationVertical),
+        ("offset", AttributeId::Offset),
+        ("onbegin", AttributeId::Onbegin),
+        ("d", AttributeId::D),
+        ("viewTarget", AttributeId::ViewTarget),
+        ("dur", AttributeId::Dur),
+        ("local", AttributeId::Local),
+        ("method", AttributeId::Method),
+        ("mathematical", AttributeId::Mathematical),
+        ("azimuth", AttributeId::Azimuth),
+        ("to", AttributeId::To),
+        ("order", AttributeId::Order),
+        ("kernelMatrix", AttributeId::KernelMatrix),
+        ("role", AttributeId::Role),
+        ("font", AttributeId::Font),
+        ("k1", AttributeId::K1),
+        ("pointsAtY", AttributeId::PointsAtY),
+        ("show", AttributeId::Show),
+        ("stroke-linecap", AttributeId::StrokeLinecap),
+        ("cap-height", AttributeId::CapHeight),
+        ("ascent", AttributeId::Ascent),
         ("onfocusout", AttributeId::Onfocusout),
-        ("pathLength", AttributeId::PathLength),
-        ("fill", AttributeId::Fill),
-        ("color-interpolation", AttributeId::ColorInterpolation),
-        ("yChannelSelector", AttributeId::YChannelSelector),
-        ("rendering-intent", AttributeId::RenderingIntent),
-        ("contentStyleType", AttributeId::ContentStyleType),
-        ("font-style", AttributeId::FontStyle),
-        ("base", AttributeId::Base),
+        ("keyPoints", AttributeId::KeyPoints),
         ("writing-mode", AttributeId::WritingMode),
-        ("unicode-bidi", AttributeId::UnicodeBidi),
-        ("refY", AttributeId::RefY),
+        ("diffuseConstant", AttributeId::DiffuseConstant),
+        ("k3", AttributeId::K3),
+        ("accent-height", AttributeId::AccentHeight),
+        ("targetX", AttributeId::TargetX),
+        ("patternUnits", AttributeId::PatternUnits),
+        ("transform", AttributeId::Transform),
+        ("baseProfile", AttributeId::BaseProfile),
         ("title", AttributeId::Title),
-        ("result", AttributeId::Result),
-        ("cx", AttributeId::Cx),
-        ("flood-color", AttributeId::FloodColor),
-        ("stroke-dashoffset", AttributeId::StrokeDashoffset),
-        ("edgeMode", AttributeId::EdgeMode),
+        ("onabort", AttributeId::Onabort),
+        ("stop-color", AttributeId::StopColor),
+        ("horiz-adv-x", AttributeId::HorizAdvX),
+        ("bbox", AttributeId::Bbox),
+        ("transform-origin", AttributeId::TransformOrigin),
+        ("x2", AttributeId::X2),
         ("font-weight", AttributeId::FontWeight),
-        ("lang", AttributeId::Lang),
-        ("requiredExtensions", AttributeId::RequiredExtensions),
-        ("orient", AttributeId::Orient),
-        ("marker", AttributeId::Marker),
-        ("font-size", AttributeId::FontSize),
-        ("onmouseout", AttributeId::Onmouseout),
-        ("font-variant", AttributeId::FontVariant),
-        ("onzoom", AttributeId::Onzoom),
-        ("limitingConeAngle", AttributeId::LimitingConeAngle),
-        ("onrepeat", AttributeId::Onrepeat),
-        ("in", AttributeId::In),
-        ("pointsAtZ", AttributeId::PointsAtZ),
-        ("zoomAndPan", AttributeId::ZoomAndPan),
-        ("mask", AttributeId::Mask),
-        ("begin", AttributeId::Begin),
-        ("v-alphabetic", AttributeId::VAlphabetic),
-        ("keyPoints", AttributeId::KeyPoints),
-        ("g2", AttributeId::G2),
-        ("glyphRef", AttributeId::GlyphRef),
-        ("name", AttributeId::Name),
-        ("visibility", AttributeId::Visibility),
-        ("ideographic", AttributeId::Ideographic),
         ("font-stretch", AttributeId::FontStretch),
-        ("display", AttributeId::Display),
-        ("overflow", AttributeId::Overflow),
-        ("stroke-linecap", AttributeId::StrokeLinecap),
-        ("color-rendering", AttributeId::ColorRendering),
-        ("stop-opacity", AttributeId::StopOpacity),
-        ("accumulate", AttributeId::Accumulate),
-        ("word-spacing", AttributeId::WordSpacing),
-        ("glyph-orientation-horizontal", AttributeId::GlyphOrientationHorizontal),
-        ("tableValues", AttributeId::TableValues),
-        ("line-height", AttributeId::LineHeight),
-        ("kerning", AttributeId::Kerning),
-        ("enable-background", AttributeId::EnableBackground),
+        ("fr", AttributeId::Fr),
+        ("z", AttributeId::Z),
         ("x-height", AttributeId::XHeight),
-        ("gradientUnits", AttributeId::GradientUnits),
-        ("stemv", AttributeId::Stemv),
-        ("viewBox", AttributeId::ViewBox),
-        ("glyph-name", AttributeId::GlyphName),
-        ("k3", AttributeId::K3),
+        ("patternContentUnits", AttributeId::PatternContentUnits),
+        ("stdDeviation", AttributeId::StdDeviation),
         ("textLength", AttributeId::TextLength),
-        ("preserveAlpha", AttributeId::PreserveAlpha),
-        ("onload", AttributeId::Onload),
-        ("operator", AttributeId::Operator),
-        ("horiz-adv-x", AttributeId::HorizAdvX),
-        ("v-ideographic", AttributeId::VIdeographic),
-        ("cap-height", AttributeId::CapHeight),
-        ("restart", AttributeId::Restart),
-        ("fy", AttributeId::Fy),
-        ("intercept", AttributeId::Intercept),
-        ("fx", AttributeId::Fx),
-        ("alphabetic", AttributeId::Alphabetic),
+        ("stroke-dasharray", AttributeId::StrokeDasharray),
+        ("tableValues", AttributeId::TableValues),
+        ("gradientTransform", AttributeId::GradientTransform),
+        ("repeatCount", AttributeId::RepeatCount),
+        ("ideographic", AttributeId::Ideographic),
+        ("fill", AttributeId::Fill),
         ("onscroll", AttributeId::Onscroll),
-        ("end", AttributeId::End),
-        ("color", AttributeId::Color),
-        ("accent-height", AttributeId::AccentHeight),
-        ("patternContentUnits", AttributeId::PatternContentUnits),
-        ("numOctaves", AttributeId::NumOctaves),
-        ("x2", AttributeId::X2),
-        ("units-per-em", AttributeId::UnitsPerEm),
+        ("kerning", AttributeId::Kerning),
+        ("points", AttributeId::Points),
+        ("from", AttributeId::From),
+        ("dominant-baseline", AttributeId::DominantBaseline),
         ("stroke-linejoin", AttributeId::StrokeLinejoin),
-        ("attributeName", AttributeId::AttributeName),
-        ("mathematical", AttributeId::Mathematical),
-        ("underline-thickness", AttributeId::UnderlineThickness),
-        ("ascent", AttributeId::Ascent),
-        ("orientation", AttributeId::Orientation),
-        ("keySplines", AttributeId::KeySplines),
-        ("hanging", AttributeId::Hanging),
-        ("z", AttributeId::Z),
-        ("primitiveUnits", AttributeId::PrimitiveUnits),
-        ("diffuseConstant", AttributeId::DiffuseConstant),
-        ("strikethrough-thickness", AttributeId::StrikethroughThickness),
-        ("cursor", AttributeId::Cursor),
-        ("marker-end", AttributeId::MarkerEnd),
-        ("clip-rule", AttributeId::ClipRule),
-        ("marker-mid", AttributeId::MarkerMid),
-        ("x1", AttributeId::X1),
-        ("requiredFeatures", AttributeId::RequiredFeatures),
-        ("alignment-baseline", AttributeId::AlignmentBaseline),
-        ("unicode-range", AttributeId::UnicodeRange),
-        ("stop-color", AttributeId::StopColor),
+        ("markerWidth", AttributeId::MarkerWidth),
+        ("pointsAtX", AttributeId::PointsAtX),
         ("elevation", AttributeId::Elevation),
-        ("k2", AttributeId::K2),
-        ("dy", AttributeId::Dy),
-        ("mode", AttributeId::Mode),
-        ("repeatDur", AttributeId::RepeatDur),
-        ("actuate", AttributeId::Actuate),
-        ("by", AttributeId::By),
-        ("pointsAtY", AttributeId::PointsAtY),
-        ("r", AttributeId::R),
-        ("additive", AttributeId::Additive),
-        ("rotate", AttributeId::Rotate),
-        ("slope", AttributeId::Slope),
-        ("media", AttributeId::Media),
-        ("maskContentUnits", AttributeId::MaskContentUnits),
-        ("spreadMethod", AttributeId::SpreadMethod),
-        ("filter", AttributeId::Filter),
-        ("text-rendering", AttributeId::TextRendering),
-        ("underline-position", AttributeId::UnderlinePosition),
-        ("onabort", AttributeId::Onabort),
-        ("dur", AttributeId::Dur),
-        ("xlink", AttributeId::Xlink),
-        ("stroke-miterlimit", AttributeId::StrokeMiterlimit),
-        ("onerror", AttributeId::Onerror),
-        ("onend", AttributeId::Onend),
-        ("stroke-dasharray", AttributeId::StrokeDasharray),
-        ("d", AttributeId::D),
+        ("limitingConeAngle", AttributeId::LimitingConeAngle),
+        ("stroke", AttributeId::Stroke),
+        ("clip", AttributeId::Clip),
+        ("y", AttributeId::Y),
+        ("clip-rule", AttributeId::ClipRule),
         ("preserveAspectRatio", AttributeId::PreserveAspectRatio),
-        ("standalone", AttributeId::Standalone),
-        ("transform", AttributeId::Transform),
+        ("restart", AttributeId::Restart),
+        ("word-spacing", AttributeId::WordSpacing),
+        ("display", AttributeId::Display),
+        ("style", AttributeId::Style),
+        ("vector-effect", AttributeId::VectorEffect),
+        ("preserveAlpha", AttributeId::PreserveAlpha),
+        ("clip-path", AttributeId::ClipPath),
+        ("cy", AttributeId::Cy),
+        ("color-rendering", AttributeId::ColorRendering),
+        ("filterRes", AttributeId::FilterRes),
+        ("maskContentUnits", AttributeId::MaskContentUnits),
+        ("rx", AttributeId::Rx),
+        ("r", AttributeId::R),
+        ("v-alphabetic", AttributeId::VAlphabetic),
+        ("glyph-orientation-horizontal", AttributeId::GlyphOrientationHorizontal),
+        ("solid-opacity", AttributeId::SolidOpacity),
+        ("isolation", AttributeId::Isolation),
+        ("by", AttributeId::By),
+        ("end", AttributeId::End),
         ("marker-start", AttributeId::MarkerStart),
+        ("filterUnits", AttributeId::FilterUnits),
+        ("marker", AttributeId::Marker),
+        ("origin", AttributeId::Origin),
+        ("arcrole", AttributeId::Arcrole),
+        ("scale", AttributeId::Scale),
+        ("alignment-baseline", AttributeId::AlignmentBaseline),
         ("u1", AttributeId::U1),
-        ("overline-thickness", AttributeId::OverlineThickness),
-        ("onunload", AttributeId::Onunload),
     ],
 };
 
@@ -849,6 +890,856 @@ impl fmt::Display for AttributeId {
     }
 }
 
+impl ElementId {
+    /// Checks that the attribute can be set on this element.
+    ///
+    /// Based on the SVG 1.1 specification.
+    /// CSS properties without a presentation attribute are allowed too.
+    pub fn allows_attribute(&self, aid: AttributeId) -> bool {
+        match *self {
+            ElementId::A => is_graphics_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::Target
+                => true,
+                _ => false,
+            },
+            ElementId::AltGlyph => is_text_content_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Dx
+                | AttributeId::Dy
+                | AttributeId::GlyphRef
+                | AttributeId::Format
+                | AttributeId::Rotate
+                => true,
+                _ => false,
+            },
+            ElementId::AltGlyphDef => is_core_attribute(aid),
+            ElementId::AltGlyphItem => is_core_attribute(aid),
+            ElementId::Animate => is_core_attribute(aid) || is_conditional_attribute(aid) || is_animation_event_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || is_presentation_attribute(aid) || is_animation_target_attribute(aid) || is_animation_timing_attribute(aid) || is_animation_value_attribute(aid) || is_animation_addition_attribute(aid),
+            ElementId::AnimateColor => is_core_attribute(aid) || is_conditional_attribute(aid) || is_animation_event_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || is_presentation_attribute(aid) || is_animation_target_attribute(aid) || is_animation_timing_attribute(aid) || is_animation_value_attribute(aid) || is_animation_addition_attribute(aid),
+            ElementId::AnimateMotion => is_core_attribute(aid) || is_conditional_attribute(aid) || is_animation_event_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || is_animation_timing_attribute(aid) || is_animation_value_attribute(aid) || is_animation_addition_attribute(aid) || match aid {
+                  AttributeId::Path
+                | AttributeId::KeyPoints
+                | AttributeId::Rotate
+                | AttributeId::Origin
+                => true,
+                _ => false,
+            },
+            ElementId::AnimateTransform => is_core_attribute(aid) || is_conditional_attribute(aid) || is_animation_event_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || is_animation_target_attribute(aid) || is_animation_timing_attribute(aid) || is_animation_value_attribute(aid) || is_animation_addition_attribute(aid) || match aid {
+                  AttributeId::Type
+                => true,
+                _ => false,
+            },
+            ElementId::Circle => is_graphics_attribute(aid) || match aid {
+                  AttributeId::Cx
+                | AttributeId::Cy
+                | AttributeId::R
+                => true,
+                _ => false,
+            },
+            ElementId::ClipPath => is_core_attribute(aid) || is_conditional_attribute(aid) || is_style_attribute(aid) || is_external_attribute(aid) || is_presentation_attribute(aid) || match aid {
+                  AttributeId::Transform
+                | AttributeId::ClipPathUnits
+                => true,
+                _ => false,
+            },
+            ElementId::ColorProfile => is_core_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::Local
+                | AttributeId::Name
+                | AttributeId::RenderingIntent
+                => true,
+                _ => false,
+            },
+            ElementId::Cursor => is_core_attribute(aid) || is_conditional_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                => true,
+                _ => false,
+            },
+            ElementId::Defs => is_graphics_attribute(aid),
+            ElementId::Desc => is_core_attribute(aid) || is_style_attribute(aid),
+            ElementId::Ellipse => is_graphics_attribute(aid) || match aid {
+                  AttributeId::Cx
+                | AttributeId::Cy
+                | AttributeId::Rx
+                | AttributeId::Ry
+                => true,
+                _ => false,
+            },
+            ElementId::FeBlend => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::In2
+                | AttributeId::Mode
+                => true,
+                _ => false,
+            },
+            ElementId::FeColorMatrix => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::Type
+                | AttributeId::Values
+                => true,
+                _ => false,
+            },
+            ElementId::FeComponentTransfer => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                => true,
+                _ => false,
+            },
+            ElementId::FeComposite => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::In2
+                | AttributeId::Operator
+                | AttributeId::K1
+                | AttributeId::K2
+                | AttributeId::K3
+                | AttributeId::K4
+                => true,
+                _ => false,
+            },
+            ElementId::FeConvolveMatrix => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::Order
+                | AttributeId::KernelMatrix
+                | AttributeId::Divisor
+                | AttributeId::Bias
+                | AttributeId::TargetX
+                | AttributeId::TargetY
+                | AttributeId::EdgeMode
+                | AttributeId::KernelUnitLength
+                | AttributeId::PreserveAlpha
+                => true,
+                _ => false,
+            },
+            ElementId::FeDiffuseLighting => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::SurfaceScale
+                | AttributeId::DiffuseConstant
+                | AttributeId::KernelUnitLength
+                => true,
+                _ => false,
+            },
+            ElementId::FeDisplacementMap => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::In2
+                | AttributeId::Scale
+                | AttributeId::XChannelSelector
+                | AttributeId::YChannelSelector
+                => true,
+                _ => false,
+            },
+            ElementId::FeDistantLight => is_core_attribute(aid) || match aid {
+                  AttributeId::Azimuth
+                | AttributeId::Elevation
+                => true,
+                _ => false,
+            },
+            ElementId::FeFlood => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid),
+            ElementId::FeFuncA => is_core_attribute(aid) || is_transfer_function_attribute(aid),
+            ElementId::FeFuncB => is_core_attribute(aid) || is_transfer_function_attribute(aid),
+            ElementId::FeFuncG => is_core_attribute(aid) || is_transfer_function_attribute(aid),
+            ElementId::FeFuncR => is_core_attribute(aid) || is_transfer_function_attribute(aid),
+            ElementId::FeGaussianBlur => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::StdDeviation
+                => true,
+                _ => false,
+            },
+            ElementId::FeImage => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::PreserveAspectRatio
+                => true,
+                _ => false,
+            },
+            ElementId::FeMerge => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid),
+            ElementId::FeMergeNode => is_core_attribute(aid) || match aid {
+                  AttributeId::In
+                => true,
+                _ => false,
+            },
+            ElementId::FeMorphology => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::Operator
+                | AttributeId::Radius
+                => true,
+                _ => false,
+            },
+            ElementId::FeOffset => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::Dx
+                | AttributeId::Dy
+                => true,
+                _ => false,
+            },
+            ElementId::FePointLight => is_core_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Z
+                => true,
+                _ => false,
+            },
+            ElementId::FeSpecularLighting => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                | AttributeId::SurfaceScale
+                | AttributeId::SpecularConstant
+                | AttributeId::SpecularExponent
+                | AttributeId::KernelUnitLength
+                => true,
+                _ => false,
+            },
+            ElementId::FeSpotLight => is_core_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Z
+                | AttributeId::PointsAtX
+                | AttributeId::PointsAtY
+                | AttributeId::PointsAtZ
+                | AttributeId::SpecularExponent
+                | AttributeId::LimitingConeAngle
+                => true,
+                _ => false,
+            },
+            ElementId::FeTile => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::In
+                => true,
+                _ => false,
+            },
+            ElementId::FeTurbulence => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_filter_primitive_attribute(aid) || match aid {
+                  AttributeId::BaseFrequency
+                | AttributeId::NumOctaves
+                | AttributeId::Seed
+                | AttributeId::StitchTiles
+                | AttributeId::Type
+                => true,
+                _ => false,
+            },
+            ElementId::Filter => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                | AttributeId::FilterRes
+                | AttributeId::FilterUnits
+                | AttributeId::PrimitiveUnits
+                => true,
+                _ => false,
+            },
+            ElementId::Font => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::HorizOriginX
+                | AttributeId::HorizOriginY
+                | AttributeId::HorizAdvX
+                | AttributeId::VertOriginX
+                | AttributeId::VertOriginY
+                | AttributeId::VertAdvY
+                => true,
+                _ => false,
+            },
+            ElementId::FontFace => is_core_attribute(aid) || match aid {
+                  AttributeId::FontFamily
+                | AttributeId::FontStyle
+                | AttributeId::FontVariant
+                | AttributeId::FontWeight
+                | AttributeId::FontStretch
+                | AttributeId::FontSize
+                | AttributeId::UnicodeRange
+                | AttributeId::UnitsPerEm
+                | AttributeId::Panose1
+                | AttributeId::Stemv
+                | AttributeId::Stemh
+                | AttributeId::Slope
+                | AttributeId::CapHeight
+                | AttributeId::XHeight
+                | AttributeId::AccentHeight
+                | AttributeId::Ascent
+                | AttributeId::Descent
+                | AttributeId::Widths
+                | AttributeId::Bbox
+                | AttributeId::Ideographic
+                | AttributeId::Alphabetic
+                | AttributeId::Mathematical
+                | AttributeId::Hanging
+                | AttributeId::VIdeographic
+                | AttributeId::VAlphabetic
+                | AttributeId::VMathematical
+                | AttributeId::VHanging
+                | AttributeId::UnderlinePosition
+                | AttributeId::UnderlineThickness
+                | AttributeId::StrikethroughPosition
+                | AttributeId::StrikethroughThickness
+                | AttributeId::OverlinePosition
+                | AttributeId::OverlineThickness
+                => true,
+                _ => false,
+            },
+            ElementId::FontFaceFormat => is_core_attribute(aid) || match aid {
+                  AttributeId::String
+                => true,
+                _ => false,
+            },
+            ElementId::FontFaceName => is_core_attribute(aid) || match aid {
+                  AttributeId::Name
+                => true,
+                _ => false,
+            },
+            ElementId::FontFaceSrc => is_core_attribute(aid),
+            ElementId::FontFaceUri => is_core_attribute(aid) || is_xlink_attribute(aid),
+            ElementId::ForeignObject => is_graphics_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                => true,
+                _ => false,
+            },
+            ElementId::G => is_graphics_attribute(aid),
+            ElementId::Glyph => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || match aid {
+                  AttributeId::D
+                | AttributeId::HorizAdvX
+                | AttributeId::VertOriginX
+                | AttributeId::VertOriginY
+                | AttributeId::VertAdvY
+                | AttributeId::Unicode
+                | AttributeId::GlyphName
+                | AttributeId::Orientation
+                | AttributeId::ArabicForm
+                | AttributeId::Lang
+                => true,
+                _ => false,
+            },
+            ElementId::GlyphRef => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Dx
+                | AttributeId::Dy
+                | AttributeId::GlyphRef
+                | AttributeId::Format
+                => true,
+                _ => false,
+            },
+            ElementId::Hatch => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Pitch
+                | AttributeId::Rotate
+                | AttributeId::HatchUnits
+                | AttributeId::HatchContentUnits
+                | AttributeId::Transform
+                => true,
+                _ => false,
+            },
+            ElementId::Hatchpath => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || match aid {
+                  AttributeId::D
+                | AttributeId::Offset
+                => true,
+                _ => false,
+            },
+            ElementId::Hkern => is_core_attribute(aid) || match aid {
+                  AttributeId::U1
+                | AttributeId::G1
+                | AttributeId::U2
+                | AttributeId::G2
+                | AttributeId::K
+                => true,
+                _ => false,
+            },
+            ElementId::Image => is_graphics_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::PreserveAspectRatio
+                | AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                => true,
+                _ => false,
+            },
+            ElementId::Line => is_graphics_attribute(aid) || match aid {
+                  AttributeId::X1
+                | AttributeId::Y1
+                | AttributeId::X2
+                | AttributeId::Y2
+                => true,
+                _ => false,
+            },
+            ElementId::LinearGradient => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::X1
+                | AttributeId::Y1
+                | AttributeId::X2
+                | AttributeId::Y2
+                | AttributeId::GradientUnits
+                | AttributeId::GradientTransform
+                | AttributeId::SpreadMethod
+                => true,
+                _ => false,
+            },
+            ElementId::Marker => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::ViewBox
+                | AttributeId::PreserveAspectRatio
+                | AttributeId::RefX
+                | AttributeId::RefY
+                | AttributeId::MarkerUnits
+                | AttributeId::MarkerWidth
+                | AttributeId::MarkerHeight
+                | AttributeId::Orient
+                => true,
+                _ => false,
+            },
+            ElementId::Mask => is_core_attribute(aid) || is_conditional_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                | AttributeId::MaskUnits
+                | AttributeId::MaskContentUnits
+                => true,
+                _ => false,
+            },
+            ElementId::Mesh => is_graphics_attribute(aid) || is_xlink_attribute(aid),
+            ElementId::Meshgradient => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Type
+                | AttributeId::GradientUnits
+                | AttributeId::GradientTransform
+                => true,
+                _ => false,
+            },
+            ElementId::Meshpatch => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid),
+            ElementId::Meshrow => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid),
+            ElementId::Metadata => is_core_attribute(aid),
+            ElementId::MissingGlyph => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || match aid {
+                  AttributeId::D
+                | AttributeId::HorizAdvX
+                | AttributeId::VertOriginX
+                | AttributeId::VertOriginY
+                | AttributeId::VertAdvY
+                => true,
+                _ => false,
+            },
+            ElementId::Mpath => is_core_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid),
+            ElementId::Path => is_graphics_attribute(aid) || match aid {
+                  AttributeId::D
+                | AttributeId::PathLength
+                => true,
+                _ => false,
+            },
+            ElementId::Pattern => is_core_attribute(aid) || is_conditional_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::ViewBox
+                | AttributeId::PreserveAspectRatio
+                | AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                | AttributeId::PatternUnits
+                | AttributeId::PatternContentUnits
+                | AttributeId::PatternTransform
+                => true,
+                _ => false,
+            },
+            ElementId::Polygon => is_graphics_attribute(aid) || match aid {
+                  AttributeId::Points
+                => true,
+                _ => false,
+            },
+            ElementId::Polyline => is_graphics_attribute(aid) || match aid {
+                  AttributeId::Points
+                => true,
+                _ => false,
+            },
+            ElementId::RadialGradient => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::Cx
+                | AttributeId::Cy
+                | AttributeId::R
+                | AttributeId::Fx
+                | AttributeId::Fy
+                | AttributeId::Fr
+                | AttributeId::GradientUnits
+                | AttributeId::GradientTransform
+                | AttributeId::SpreadMethod
+                => true,
+                _ => false,
+            },
+            ElementId::Rect => is_graphics_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                | AttributeId::Rx
+                | AttributeId::Ry
+                => true,
+                _ => false,
+            },
+            ElementId::Script => is_core_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::Type
+                => true,
+                _ => false,
+            },
+            ElementId::Set => is_core_attribute(aid) || is_conditional_attribute(aid) || is_animation_event_attribute(aid) || is_xlink_attribute(aid) || is_external_attribute(aid) || is_animation_target_attribute(aid) || is_animation_timing_attribute(aid) || match aid {
+                  AttributeId::To
+                => true,
+                _ => false,
+            },
+            ElementId::Solidcolor => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid),
+            ElementId::Stop => is_core_attribute(aid) || is_presentation_attribute(aid) || is_style_attribute(aid) || match aid {
+                  AttributeId::Offset
+                => true,
+                _ => false,
+            },
+            ElementId::Style => is_core_attribute(aid) || match aid {
+                  AttributeId::Type
+                | AttributeId::Media
+                | AttributeId::Title
+                => true,
+                _ => false,
+            },
+            ElementId::Svg => is_core_attribute(aid) || is_conditional_attribute(aid) || is_style_attribute(aid) || is_presentation_attribute(aid) || is_document_event_attribute(aid) || is_graphical_event_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                | AttributeId::ViewBox
+                | AttributeId::PreserveAspectRatio
+                | AttributeId::ZoomAndPan
+                | AttributeId::Version
+                | AttributeId::BaseProfile
+                | AttributeId::ContentScriptType
+                | AttributeId::ContentStyleType
+                => true,
+                _ => false,
+            },
+            ElementId::Switch => is_graphics_attribute(aid),
+            ElementId::Symbol => is_core_attribute(aid) || is_style_attribute(aid) || is_external_attribute(aid) || is_graphical_event_attribute(aid) || is_presentation_attribute(aid) || match aid {
+                  AttributeId::ViewBox
+                | AttributeId::PreserveAspectRatio
+                | AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                | AttributeId::RefX
+                | AttributeId::RefY
+                => true,
+                _ => false,
+            },
+            ElementId::Text => is_text_content_attribute(aid) || match aid {
+                  AttributeId::Transform
+                | AttributeId::LengthAdjust
+                | AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Dx
+                | AttributeId::Dy
+                | AttributeId::Rotate
+                | AttributeId::TextLength
+                => true,
+                _ => false,
+            },
+            ElementId::TextPath => is_text_content_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::StartOffset
+                | AttributeId::Method
+                | AttributeId::Spacing
+                => true,
+                _ => false,
+            },
+            ElementId::Title => is_core_attribute(aid) || is_style_attribute(aid),
+            ElementId::Tref => is_text_content_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Dx
+                | AttributeId::Dy
+                | AttributeId::Rotate
+                | AttributeId::TextLength
+                | AttributeId::LengthAdjust
+                => true,
+                _ => false,
+            },
+            ElementId::Tspan => is_text_content_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Dx
+                | AttributeId::Dy
+                | AttributeId::Rotate
+                | AttributeId::TextLength
+                | AttributeId::LengthAdjust
+                => true,
+                _ => false,
+            },
+            ElementId::Use => is_graphics_attribute(aid) || is_xlink_attribute(aid) || match aid {
+                  AttributeId::X
+                | AttributeId::Y
+                | AttributeId::Width
+                | AttributeId::Height
+                => true,
+                _ => false,
+            },
+            ElementId::View => is_core_attribute(aid) || is_external_attribute(aid) || match aid {
+                  AttributeId::ViewBox
+                | AttributeId::PreserveAspectRatio
+                | AttributeId::ZoomAndPan
+                | AttributeId::ViewTarget
+                => true,
+                _ => false,
+            },
+            ElementId::Vkern => is_core_attribute(aid) || match aid {
+                  AttributeId::U1
+                | AttributeId::G1
+                | AttributeId::U2
+                | AttributeId::G2
+                | AttributeId::K
+                => true,
+                _ => false,
+            },
+        }
+    }
+}
+
+fn is_core_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Id
+        | AttributeId::Base
+        | AttributeId::Lang
+        | AttributeId::Space
+        => true,
+        _ => false,
+    }
+}
+
+fn is_conditional_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::RequiredFeatures
+        | AttributeId::RequiredExtensions
+        | AttributeId::SystemLanguage
+        => true,
+        _ => false,
+    }
+}
+
+fn is_style_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Class
+        | AttributeId::Style
+        => true,
+        _ => false,
+    }
+}
+
+fn is_xlink_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Href
+        | AttributeId::Type
+        | AttributeId::Role
+        | AttributeId::Arcrole
+        | AttributeId::Title
+        | AttributeId::Show
+        | AttributeId::Actuate
+        => true,
+        _ => false,
+    }
+}
+
+fn is_external_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::ExternalResourcesRequired
+        => true,
+        _ => false,
+    }
+}
+
+fn is_document_event_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Onunload
+        | AttributeId::Onabort
+        | AttributeId::Onerror
+        | AttributeId::Onresize
+        | AttributeId::Onscroll
+        | AttributeId::Onzoom
+        => true,
+        _ => false,
+    }
+}
+
+fn is_graphical_event_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Onfocusin
+        | AttributeId::Onfocusout
+        | AttributeId::Onactivate
+        | AttributeId::Onclick
+        | AttributeId::Onmousedown
+        | AttributeId::Onmouseup
+        | AttributeId::Onmouseover
+        | AttributeId::Onmousemove
+        | AttributeId::Onmouseout
+        | AttributeId::Onload
+        => true,
+        _ => false,
+    }
+}
+
+fn is_animation_event_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Onbegin
+        | AttributeId::Onend
+        | AttributeId::Onrepeat
+        | AttributeId::Onload
+        => true,
+        _ => false,
+    }
+}
+
+fn is_animation_target_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::AttributeType
+        | AttributeId::AttributeName
+        => true,
+        _ => false,
+    }
+}
+
+fn is_animation_timing_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Begin
+        | AttributeId::Dur
+        | AttributeId::End
+        | AttributeId::Min
+        | AttributeId::Max
+        | AttributeId::Restart
+        | AttributeId::RepeatCount
+        | AttributeId::RepeatDur
+        | AttributeId::Fill
+        => true,
+        _ => false,
+    }
+}
+
+fn is_animation_value_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::CalcMode
+        | AttributeId::Values
+        | AttributeId::KeyTimes
+        | AttributeId::KeySplines
+        | AttributeId::From
+        | AttributeId::To
+        | AttributeId::By
+        => true,
+        _ => false,
+    }
+}
+
+fn is_animation_addition_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Additive
+        | AttributeId::Accumulate
+        => true,
+        _ => false,
+    }
+}
+
+fn is_filter_primitive_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::X
+        | AttributeId::Y
+        | AttributeId::Width
+        | AttributeId::Height
+        | AttributeId::Result
+        => true,
+        _ => false,
+    }
+}
+
+fn is_transfer_function_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::Type
+        | AttributeId::TableValues
+        | AttributeId::Slope
+        | AttributeId::Intercept
+        | AttributeId::Amplitude
+        | AttributeId::Exponent
+        | AttributeId::Offset
+        => true,
+        _ => false,
+    }
+}
+
+fn is_presentation_attribute(aid: AttributeId) -> bool {
+    match aid {
+          AttributeId::AlignmentBaseline
+        | AttributeId::BaselineShift
+        | AttributeId::Clip
+        | AttributeId::ClipPath
+        | AttributeId::ClipRule
+        | AttributeId::Color
+        | AttributeId::ColorInterpolation
+        | AttributeId::ColorInterpolationFilters
+        | AttributeId::ColorProfile
+        | AttributeId::ColorRendering
+        | AttributeId::Cursor
+        | AttributeId::Direction
+        | AttributeId::Display
+        | AttributeId::DominantBaseline
+        | AttributeId::EnableBackground
+        | AttributeId::Fill
+        | AttributeId::FillOpacity
+        | AttributeId::FillRule
+        | AttributeId::Filter
+        | AttributeId::FloodColor
+        | AttributeId::FloodOpacity
+        | AttributeId::Font
+        | AttributeId::FontFamily
+        | AttributeId::FontSize
+        | AttributeId::FontSizeAdjust
+        | AttributeId::FontStretch
+        | AttributeId::FontStyle
+        | AttributeId::FontVariant
+        | AttributeId::FontWeight
+        | AttributeId::GlyphOrientationHorizontal
+        | AttributeId::GlyphOrientationVertical
+        | AttributeId::ImageRendering
+        | AttributeId::Kerning
+        | AttributeId::LetterSpacing
+        | AttributeId::LightingColor
+        | AttributeId::Marker
+        | AttributeId::MarkerEnd
+        | AttributeId::MarkerMid
+        | AttributeId::MarkerStart
+        | AttributeId::Mask
+        | AttributeId::Opacity
+        | AttributeId::Overflow
+        | AttributeId::PaintOrder
+        | AttributeId::PointerEvents
+        | AttributeId::ShapeRendering
+        | AttributeId::SolidColor
+        | AttributeId::SolidOpacity
+        | AttributeId::StopColor
+        | AttributeId::StopOpacity
+        | AttributeId::Stroke
+        | AttributeId::StrokeDasharray
+        | AttributeId::StrokeDashoffset
+        | AttributeId::StrokeLinecap
+        | AttributeId::StrokeLinejoin
+        | AttributeId::StrokeMiterlimit
+        | AttributeId::StrokeOpacity
+        | AttributeId::StrokeWidth
+        | AttributeId::TextAnchor
+        | AttributeId::TextDecoration
+        | AttributeId::TextRendering
+        | AttributeId::UnicodeBidi
+        | AttributeId::VectorEffect
+        | AttributeId::Visibility
+        | AttributeId::WordSpacing
+        | AttributeId::WritingMode
+        | AttributeId::Isolation
+        | AttributeId::MixBlendMode
+        => true,
+        _ => false,
+    }
+}
+
+fn is_graphics_attribute(aid: AttributeId) -> bool {
+    is_core_attribute(aid) || is_conditional_attribute(aid) || is_style_attribute(aid) || is_external_attribute(aid) || is_graphical_event_attribute(aid) || is_presentation_attribute(aid) || match aid {
+          AttributeId::Transform
+        => true,
+        _ => false,
+    }
+}
+
+fn is_text_content_attribute(aid: AttributeId) -> bool {
+    is_core_attribute(aid) || is_conditional_attribute(aid) || is_style_attribute(aid) || is_external_attribute(aid) || is_graphical_event_attribute(aid) || is_presentation_attribute(aid)
+}
+
 // A stripped down `phf` crate fork.
 //
 // https://github.com/sfackler/rust-phf
@@ -1,7 +1,12 @@
 use std::iter::FilterMap;
 use std::cell::{Ref, RefMut};
+use std::mem;
 
 use crate::{
+    bbox,
+    document,
+    link_graph,
+    shapes,
     tree,
     Attribute,
     AttributeId,
@@ -9,13 +14,17 @@ use crate::{
     AttributeQNameRef,
     Attributes,
     AttributeValue,
+    BorrowError,
     ElementId,
     Error,
     NodeData,
+    NormalizeOptions,
     NodeType,
     PaintFallback,
+    PathNormalize,
     QName,
     QNameRef,
+    Rect,
     TagName,
     TagNameRef,
 };
@@ -173,6 +182,17 @@ impl Node {
         Ref::map(self.borrow(), |d| &d.text)
     }
 
+    /// Returns a text data of the node.
+    ///
+    /// Non-panicking version of [`text`](#method.text).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently mutably borrowed.
+    pub fn try_text(&self) -> Result<Ref<'_, String>, BorrowError> {
+        Ok(Ref::map(self.try_borrow()?, |d| &d.text))
+    }
+
     /// Returns a mutable text data of the node.
     ///
     /// Nodes with `Element` type can't contain text data.
@@ -203,6 +223,17 @@ impl Node {
         Ref::map(self.borrow(), |d| &d.id)
     }
 
+    /// Returns an ID of the element node.
+    ///
+    /// Non-panicking version of [`id`](#method.id).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently mutably borrowed.
+    pub fn try_id(&self) -> Result<Ref<'_, String>, BorrowError> {
+        Ok(Ref::map(self.try_borrow()?, |d| &d.id))
+    }
+
     /// Returns `true` if node has a not empty ID.
     ///
     /// # Panics
@@ -218,12 +249,41 @@ impl Node {
     ///
     /// # Panics
     ///
-    /// Panics if the node is currently borrowed.
+    /// Panics if the node is currently borrowed
+    /// or one of its ancestors is currently mutably borrowed.
     pub fn set_id<S: Into<String>>(&mut self, id: S) {
+        self.try_set_id(id).unwrap();
+    }
+
+    /// Sets an ID of the element.
+    ///
+    /// Non-panicking version of [`set_id`](#method.set_id).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently borrowed
+    /// or one of its ancestors is currently mutably borrowed.
+    pub fn try_set_id<S: Into<String>>(&mut self, id: S) -> Result<(), BorrowError> {
         // TODO: check that it's unique.
+        self.check_borrow_mut()?;
         debug_assert_eq!(self.node_type(), NodeType::Element);
-        self.borrow_mut().id = id.into().to_owned();
 
+        // Only nodes that are part of the document tree are indexed.
+        let in_tree = document::is_in_tree(self)?;
+
+        let id = id.into();
+        let old_id = mem::replace(&mut self.borrow_mut().id, id.clone());
+
+        let index = self.borrow().id_index.upgrade();
+        if let Some(index) = index {
+            if in_tree {
+                let mut index = index.borrow_mut();
+                index.remove(&old_id, self);
+                index.insert(&id, self);
+            }
+        }
+
+        Ok(())
     }
 
     /// Returns `true` if node has an `Element` type and an SVG tag name.
@@ -251,6 +311,17 @@ impl Node {
         Ref::map(self.borrow(), |d| &d.tag_name)
     }
 
+    /// Returns a tag name of the element node.
+    ///
+    /// Non-panicking version of [`tag_name`](#method.tag_name).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently mutably borrowed.
+    pub fn try_tag_name(&self) -> Result<Ref<'_, TagName>, BorrowError> {
+        Ok(Ref::map(self.try_borrow()?, |d| &d.tag_name))
+    }
+
     /// Returns a tag name id of the SVG element node.
     ///
     /// # Panics
@@ -306,6 +377,17 @@ impl Node {
         Ref::map(self.borrow(), |d| &d.attributes)
     }
 
+    /// Returns a reference to the `Attributes` of the current node.
+    ///
+    /// Non-panicking version of [`attributes`](#method.attributes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently mutably borrowed.
+    pub fn try_attributes(&self) -> Result<Ref<'_, Attributes>, BorrowError> {
+        Ok(Ref::map(self.try_borrow()?, |d| &d.attributes))
+    }
+
     /// Returns a mutable reference to the `Attributes` of the current node.
     ///
     /// # Panics
@@ -315,6 +397,17 @@ impl Node {
         RefMut::map(self.borrow_mut(), |d| &mut d.attributes)
     }
 
+    /// Returns a mutable reference to the `Attributes` of the current node.
+    ///
+    /// Non-panicking version of [`attributes_mut`](#method.attributes_mut).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently borrowed.
+    pub fn try_attributes_mut(&mut self) -> Result<RefMut<'_, Attributes>, BorrowError> {
+        Ok(RefMut::map(self.try_borrow_mut()?, |d| &mut d.attributes))
+    }
+
     /// Returns `true` if the node has an attribute with such `id`.
     ///
     /// # Panics
@@ -361,10 +454,13 @@ impl Node {
     ///
     /// - [`ElementMustHaveAnId`]
     /// - [`ElementCrosslink`]
+    /// - [`NodeBorrowed`] if one of the nodes visited during the links cycle detection
+    ///   is currently mutably borrowed.
     ///
     /// # Panics
     ///
     /// Panics if the node is currently borrowed.
+    /// Use [`try_set_attribute`] to avoid this.
     ///
     /// # Examples
     ///
@@ -449,12 +545,38 @@ impl Node {
     ///
     /// [`ElementMustHaveAnId`]: enum.Error.html
     /// [`ElementCrosslink`]: enum.Error.html
+    /// [`NodeBorrowed`]: enum.Error.html
+    /// [`try_set_attribute`]: #method.try_set_attribute
     pub fn set_attribute_checked<T>(&mut self, v: T) -> Result<(), Error>
         where T: Into<Attribute>
     {
         self.set_attribute_checked_impl(v.into())
     }
 
+    /// Inserts a new attribute into attributes list.
+    ///
+    /// Non-panicking version of [`set_attribute_checked`](#method.set_attribute_checked).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeBorrowed`] if the node or one of the linked nodes is currently borrowed.
+    /// - Any error produced by the `set_attribute_checked` method.
+    ///
+    /// [`NodeBorrowed`]: enum.Error.html
+    pub fn try_set_attribute<T>(&mut self, v: T) -> Result<(), Error>
+        where T: Into<Attribute>
+    {
+        let attr = v.into();
+
+        self.check_borrow_mut()?;
+        self.check_linked_node(attr.name.as_ref())?;
+        if let Some(node) = link_target(&attr.value) {
+            node.check_borrow_mut()?;
+        }
+
+        self.set_attribute_checked_impl(attr)
+    }
+
     fn set_attribute_checked_impl(&mut self, attr: Attribute) -> Result<(), Error> {
         debug_assert!(self.node_type() == NodeType::Element);
 
@@ -489,7 +611,7 @@ impl Node {
     fn set_link_attribute(
         &mut self,
         name: AttributeQName,
-        mut node: Node,
+        node: Node,
         fallback: Option<PaintFallback>,
     ) -> Result<(), Error> {
         if node.id().is_empty() {
@@ -497,15 +619,42 @@ impl Node {
         }
 
         // check for recursion
-        if *self.id() == *node.id() {
+        if *self == node {
             return Err(Error::ElementCrosslink);
         }
 
-        // check for recursion 2
-        if self.linked_nodes().iter().any(|n| *n == node) {
+        // check for indirect recursion
+        if link_graph::is_dependency(self, name.as_ref()) && link_graph::is_cyclic_link(self, &node)? {
             return Err(Error::ElementCrosslink);
         }
 
+        self.insert_link_attribute(name, node, fallback);
+
+        Ok(())
+    }
+
+    // Inserts an attribute without checking links.
+    //
+    // Used when copying links that were already validated.
+    pub(crate) fn set_attribute_unchecked(&mut self, attr: Attribute) {
+        match attr.value {
+              AttributeValue::Link(ref node)
+            | AttributeValue::FuncLink(ref node) => {
+                self.insert_link_attribute(attr.name.clone(), node.clone(), None);
+            }
+            AttributeValue::Paint(ref node, fallback) => {
+                self.insert_link_attribute(attr.name.clone(), node.clone(), fallback);
+            }
+            _ => self.set_simple_attribute(attr),
+        }
+    }
+
+    fn insert_link_attribute(
+        &mut self,
+        name: AttributeQName,
+        mut node: Node,
+        fallback: Option<PaintFallback>,
+    ) {
         // we must remove existing attribute to prevent dangling links
         self.remove_attribute(name.as_ref());
 
@@ -523,8 +672,6 @@ impl Node {
         }
 
         node.borrow_mut().linked_nodes.push(self.clone());
-
-        Ok(())
     }
 
     /// Inserts a new attribute into attributes list if it doesn't contain one.
@@ -586,6 +733,30 @@ impl Node {
         self.attributes_mut().remove(name);
     }
 
+    /// Removes an attribute from the node.
+    ///
+    /// Non-panicking version of [`remove_attribute`](#method.remove_attribute).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node or the linked node is currently borrowed.
+    pub fn try_remove_attribute<'a, N>(&mut self, name: N) -> Result<(), BorrowError>
+        where AttributeQNameRef<'a>: From<N>, N: Copy
+    {
+        self.check_borrow_mut()?;
+        self.check_linked_node(AttributeQNameRef::from(name))?;
+        self.remove_attribute(name);
+        Ok(())
+    }
+
+    // Checks that a node referenced by the attribute can be unlinked.
+    fn check_linked_node(&self, name: AttributeQNameRef) -> Result<(), BorrowError> {
+        match self.attributes().get_value(name).and_then(link_target) {
+            Some(node) => node.check_borrow_mut(),
+            None => Ok(()),
+        }
+    }
+
     /// Returns an iterator over linked nodes.
     ///
     /// See [Node::set_attribute()](#method.set_attribute) for details.
@@ -597,6 +768,17 @@ impl Node {
         Ref::map(self.borrow(), |d| &d.linked_nodes)
     }
 
+    /// Returns an iterator over linked nodes.
+    ///
+    /// Non-panicking version of [`linked_nodes`](#method.linked_nodes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently mutably borrowed.
+    pub fn try_linked_nodes(&self) -> Result<Ref<'_, Vec<Node>>, BorrowError> {
+        Ok(Ref::map(self.try_borrow()?, |d| &d.linked_nodes))
+    }
+
     /// Returns an iterator over mutable linked nodes.
     ///
     /// See [Node::set_attribute()](#method.set_attribute) for details.
@@ -629,6 +811,115 @@ impl Node {
     pub fn uses_count(&self) -> usize {
         self.linked_nodes().len()
     }
+
+    /// Returns the node's object bounding box in the canvas coordinates.
+    ///
+    /// Transforms of the node and all its ancestors are applied.
+    ///
+    /// Supports basic shapes, paths, images, `use`, containers and text.
+    /// Curves and arcs are handled exactly. Text bounding box is an approximation,
+    /// since glyph metrics are unknown.
+    ///
+    /// Returns `None` for elements without geometry, like gradients, `defs`
+    /// or elements with `display="none"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node or any of the related nodes are currently mutably borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::{Document, Rect};
+    ///
+    /// let doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <g transform='translate(10 20)'>
+    ///         <circle cx='50' cy='50' r='10'/>
+    ///     </g>
+    /// </svg>").unwrap();
+    ///
+    /// let circle = doc.root().descendants().last().unwrap();
+    /// assert_eq!(circle.bbox(), Some(Rect::new(50.0, 60.0, 20.0, 20.0)));
+    /// ```
+    pub fn bbox(&self) -> Option<Rect> {
+        bbox::calc_node_bbox(self, false)
+    }
+
+    /// Returns the node's bounding box including the stroke.
+    ///
+    /// Same as [`bbox()`](#method.bbox), but expanded by a half of the `stroke-width`.
+    /// Line joins and caps are not taken into account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node or any of the related nodes are currently mutably borrowed.
+    pub fn stroke_bbox(&self) -> Option<Rect> {
+        bbox::calc_node_bbox(self, true)
+    }
+
+    /// Replaces a basic shape with an equivalent `path` element.
+    ///
+    /// All other attributes and links are preserved. Lengths are converted into user units
+    /// and rounded `rect` corners are resolved according to the `rx`/`ry` rules.
+    /// Inherited markers are disabled for `rect`, `circle` and `ellipse`,
+    /// since they are not allowed on them.
+    ///
+    /// Returns `false` and leaves the node untouched if it is not a basic shape
+    /// or if the shape is invalid, like a `rect` with a zero `width`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node or any of its ancestors are currently borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::Document;
+    ///
+    /// let doc = Document::from_str(
+    /// "<svg xmlns='http://www.w3.org/2000/svg'>
+    ///     <rect id='r' fill='red' width='10' height='20'/>
+    /// </svg>").unwrap();
+    ///
+    /// let mut rect = doc.root().descendants().last().unwrap();
+    /// assert!(rect.convert_to_path());
+    /// assert_eq!(doc.to_string(),
+    /// "<svg xmlns=\"http://www.w3.org/2000/svg\">
+    ///     <path id=\"r\" d=\"M 0 0 H 10 V 20 H 0 Z\" fill=\"#ff0000\"/>
+    /// </svg>
+    /// ");
+    /// ```
+    pub fn convert_to_path(&mut self) -> bool {
+        shapes::convert_to_path(self)
+    }
+
+    /// Normalizes the path data of the current node.
+    ///
+    /// See [`NormalizeOptions`](struct.NormalizeOptions.html) for details.
+    ///
+    /// Returns `false` if the node doesn't have the `d` attribute with a path data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is currently borrowed.
+    pub fn normalize_path(&mut self, opt: &NormalizeOptions) -> bool {
+        let mut attrs = self.attributes_mut();
+        match attrs.get_value_mut(AttributeId::D) {
+            Some(&mut AttributeValue::Path(ref mut path)) => {
+                path.normalize(opt);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn link_target(value: &AttributeValue) -> Option<&Node> {
+    match *value {
+          AttributeValue::Link(ref node)
+        | AttributeValue::FuncLink(ref node)
+        | AttributeValue::Paint(ref node, _) => Some(node),
+        _ => None,
+    }
 }
 
 /// An iterator over SVG elements.
@@ -0,0 +1,245 @@
+// Parsing of the CSS Color Module Level 3/4 colors.
+//
+// `svgtypes` supports only the SVG 1.1 colors, which doesn't have an alpha channel.
+// So we have to parse `rgba()`, `hsl()`, `hsla()`, `hwb()`,
+// `#rgba`, `#rrggbbaa` and `transparent` manually.
+
+use std::str::FromStr;
+
+use svgtypes::{
+    Color,
+    Error,
+    Paint,
+    PaintFallback,
+    Stream,
+};
+
+/// Parses a `<paint>` value with an optional alpha.
+///
+/// Alpha of the FuncIRI fallback color is returned too.
+pub fn parse_paint<'a>(text: &'a str) -> Result<(Paint<'a>, f64), Error> {
+    if let Ok(paint) = Paint::from_str(text) {
+        return Ok((paint, 1.0));
+    }
+
+    let text = text.trim();
+    if text.starts_with("url(") {
+        let mut s = Stream::from(text);
+        let link = s.parse_func_iri()?;
+        s.skip_spaces();
+
+        // `Paint::from_str` already handles all other fallback variants.
+        let (color, alpha) = parse_color(s.slice_tail())?;
+        Ok((Paint::FuncIRI(link, Some(PaintFallback::Color(color))), alpha))
+    } else {
+        let (color, alpha) = parse_color(text)?;
+        Ok((Paint::Color(color), alpha))
+    }
+}
+
+/// Parses a `<color>` value with an optional alpha.
+pub fn parse_color(text: &str) -> Result<(Color, f64), Error> {
+    let text = text.trim();
+
+    if text.eq_ignore_ascii_case("transparent") {
+        return Ok((Color::black(), 0.0));
+    }
+
+    if text.as_bytes().first() == Some(&b'#') {
+        return parse_hex(&text[1..]);
+    }
+
+    let (name, args) = match (text.find('('), text.ends_with(')')) {
+        (Some(idx), true) => (&text[..idx], &text[idx + 1..text.len() - 1]),
+        _ => return Ok((Color::from_str(text)?, 1.0)),
+    };
+
+    let (args, alpha) = split_args(args)?;
+    let alpha = match alpha {
+        Some(alpha) => parse_alpha(alpha)?,
+        None => 1.0,
+    };
+
+    let color = match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => {
+            Color::new(
+                parse_rgb_component(args[0])?,
+                parse_rgb_component(args[1])?,
+                parse_rgb_component(args[2])?,
+            )
+        }
+        "hsl" | "hsla" => {
+            let hue = parse_hue(args[0])?;
+            let saturation = parse_fraction(args[1])?;
+            let lightness = parse_fraction(args[2])?;
+            hsl_to_rgb(hue, saturation, lightness)
+        }
+        "hwb" => {
+            let hue = parse_hue(args[0])?;
+            let whiteness = parse_fraction(args[1])?;
+            let blackness = parse_fraction(args[2])?;
+            hwb_to_rgb(hue, whiteness, blackness)
+        }
+        _ => return Err(Error::InvalidValue),
+    };
+
+    Ok((color, alpha))
+}
+
+fn parse_hex(text: &str) -> Result<(Color, f64), Error> {
+    if !text.bytes().all(|c| (c as char).is_ascii_hexdigit()) {
+        return Err(Error::InvalidValue);
+    }
+
+    let digit = |i: usize| u8::from_str_radix(&text[i..i + 1], 16).unwrap();
+    let short = |i: usize| digit(i) * 17;
+    let pair = |i: usize| digit(i) * 16 + digit(i + 1);
+
+    match text.len() {
+        3 => Ok((Color::new(short(0), short(1), short(2)), 1.0)),
+        4 => Ok((Color::new(short(0), short(1), short(2)), f64::from(short(3)) / 255.0)),
+        6 => Ok((Color::new(pair(0), pair(2), pair(4)), 1.0)),
+        8 => Ok((Color::new(pair(0), pair(2), pair(4)), f64::from(pair(6)) / 255.0)),
+        _ => Err(Error::InvalidValue),
+    }
+}
+
+// Splits function arguments into three components and an optional alpha.
+//
+// Both the legacy `r, g, b, a` and the Level 4 `r g b / a` syntaxes are supported.
+fn split_args(text: &str) -> Result<(Vec<&str>, Option<&str>), Error> {
+    let (mut args, alpha): (Vec<&str>, _) = if text.contains(',') {
+        (text.split(',').map(|s| s.trim()).collect(), None)
+    } else {
+        let mut iter = text.splitn(2, '/');
+        let args = iter.next().unwrap().split_whitespace().collect();
+        (args, iter.next().map(|s| s.trim()))
+    };
+
+    let alpha = match (args.len(), alpha) {
+        (3, alpha) => alpha,
+        (4, None) if text.contains(',') => args.pop(),
+        _ => return Err(Error::InvalidValue),
+    };
+
+    if args.iter().any(|s| s.is_empty()) {
+        return Err(Error::InvalidValue);
+    }
+
+    Ok((args, alpha))
+}
+
+// Parses a number with an optional suffix.
+fn parse_number_with_unit(text: &str) -> Result<(f64, &str), Error> {
+    // `none` is a valid component value since CSS Color Level 4.
+    if text == "none" {
+        return Ok((0.0, ""));
+    }
+
+    let mut s = Stream::from(text);
+    let n = s.parse_number()?;
+    Ok((n, s.slice_tail().trim()))
+}
+
+fn parse_rgb_component(text: &str) -> Result<u8, Error> {
+    let n = match parse_number_with_unit(text)? {
+        (n, "") => n,
+        (n, "%") => n * 255.0 / 100.0,
+        _ => return Err(Error::InvalidValue),
+    };
+
+    Ok(bound(0.0, n, 255.0).round() as u8)
+}
+
+fn parse_alpha(text: &str) -> Result<f64, Error> {
+    let n = match parse_number_with_unit(text)? {
+        (n, "") => n,
+        (n, "%") => n / 100.0,
+        _ => return Err(Error::InvalidValue),
+    };
+
+    Ok(bound(0.0, n, 1.0))
+}
+
+// Parses a saturation/lightness/whiteness/blackness value into the 0..1 range.
+fn parse_fraction(text: &str) -> Result<f64, Error> {
+    let n = match parse_number_with_unit(text)? {
+        (n, "%") | (n, "") => n / 100.0,
+        _ => return Err(Error::InvalidValue),
+    };
+
+    Ok(bound(0.0, n, 1.0))
+}
+
+// Parses a hue into degrees.
+fn parse_hue(text: &str) -> Result<f64, Error> {
+    let deg = match parse_number_with_unit(text)? {
+        (n, "") | (n, "deg") => n,
+        (n, "grad") => n * 0.9,
+        (n, "rad") => n.to_degrees(),
+        (n, "turn") => n * 360.0,
+        _ => return Err(Error::InvalidValue),
+    };
+
+    Ok(((deg % 360.0) + 360.0) % 360.0)
+}
+
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> Color {
+    let (r, g, b) = hsl_to_rgb_f64(hue, saturation, lightness);
+    Color::new(to_u8(r), to_u8(g), to_u8(b))
+}
+
+fn hwb_to_rgb(hue: f64, whiteness: f64, blackness: f64) -> Color {
+    if whiteness + blackness >= 1.0 {
+        let gray = to_u8(whiteness / (whiteness + blackness));
+        return Color::new(gray, gray, gray);
+    }
+
+    let (r, g, b) = hsl_to_rgb_f64(hue, 1.0, 0.5);
+    let f = |c: f64| to_u8(c * (1.0 - whiteness - blackness) + whiteness);
+    Color::new(f(r), f(g), f(b))
+}
+
+// https://www.w3.org/TR/css-color-3/#hsl-color
+fn hsl_to_rgb_f64(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
+    let t2 = if lightness <= 0.5 {
+        lightness * (saturation + 1.0)
+    } else {
+        lightness + saturation - lightness * saturation
+    };
+
+    let t1 = lightness * 2.0 - t2;
+    let hue = hue / 60.0;
+
+    (hue_to_rgb(t1, t2, hue + 2.0), hue_to_rgb(t1, t2, hue), hue_to_rgb(t1, t2, hue - 2.0))
+}
+
+fn hue_to_rgb(t1: f64, t2: f64, mut hue: f64) -> f64 {
+    if hue < 0.0 {
+        hue += 6.0;
+    }
+
+    if hue >= 6.0 {
+        hue -= 6.0;
+    }
+
+    if hue < 1.0 {
+        (t2 - t1) * hue + t1
+    } else if hue < 3.0 {
+        t2
+    } else if hue < 4.0 {
+        (t2 - t1) * (4.0 - hue) + t1
+    } else {
+        t1
+    }
+}
+
+#[inline]
+fn to_u8(n: f64) -> u8 {
+    (bound(0.0, n, 1.0) * 255.0).round() as u8
+}
+
+#[inline]
+fn bound(min: f64, val: f64, max: f64) -> f64 {
+    val.max(min).min(max)
+}
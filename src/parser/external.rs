@@ -0,0 +1,159 @@
+// Parsing and resolving of references to external documents.
+
+use std::collections::HashMap;
+
+use log::warn;
+
+use svgtypes::{
+    Paint,
+    PaintFallback,
+};
+
+use crate::{
+    AttributeId,
+    AttributeValue,
+    Document,
+    ExternalIri,
+    Node,
+};
+use crate::import::Importer;
+use super::ParseOptions;
+use super::color;
+
+pub struct ExternalLink {
+    pub attr_id: AttributeId,
+    pub iri: ExternalIri,
+    pub fallback: Option<PaintFallback>,
+    pub node: Node,
+}
+
+/// Parses an external IRI, like `sprite.svg#icon`.
+///
+/// Local IRI and `data:` URLs are not external.
+pub fn parse_iri(text: &str) -> Option<ExternalIri> {
+    let text = text.trim();
+    if text.starts_with("data:") {
+        return None;
+    }
+
+    let idx = text.find('#')?;
+    let (path, id) = (&text[..idx], &text[idx + 1..]);
+    if path.is_empty() || id.is_empty() {
+        return None;
+    }
+
+    Some(ExternalIri {
+        path: path.to_string(),
+        id: id.to_string(),
+    })
+}
+
+/// Parses an external FuncIRI, like `url(sprite.svg#grad)`.
+///
+/// Returns the IRI and the rest of the text.
+pub fn parse_func_iri(text: &str) -> Option<(ExternalIri, &str)> {
+    let text = text.trim();
+    if !text.starts_with("url(") {
+        return None;
+    }
+
+    let end = text.find(')')?;
+    let iri = text[4..end].trim();
+    let iri = iri.trim_matches(|c| c == '\'' || c == '"');
+
+    Some((parse_iri(iri)?, text[end + 1..].trim()))
+}
+
+/// Parses an external FuncIRI with an optional paint fallback.
+pub fn parse_paint(text: &str) -> Option<(ExternalIri, Option<PaintFallback>)> {
+    let (iri, tail) = parse_func_iri(text)?;
+    if tail.is_empty() {
+        return Some((iri, None));
+    }
+
+    // The fallback color alpha is ignored, because the paint server is preferred.
+    let fallback = match color::parse_paint(tail).ok()?.0 {
+        Paint::None => PaintFallback::None,
+        Paint::CurrentColor => PaintFallback::CurrentColor,
+        Paint::Color(c) => PaintFallback::Color(c),
+        _ => return None,
+    };
+
+    Some((iri, Some(fallback)))
+}
+
+/// Imports elements referenced by external links using
+/// the `ParseOptions::external_resolver`.
+///
+/// Links that cannot be resolved are stored as is.
+pub fn resolve_links(doc: &mut Document, links: Vec<ExternalLink>, opt: &ParseOptions) {
+    let mut docs = HashMap::new();
+    let mut importers = HashMap::new();
+
+    for mut d in links {
+        let node = match opt.external_resolver {
+            Some(ref resolver) => {
+                import_element(doc, &d.iri, resolver, &mut docs, &mut importers)
+            }
+            None => None,
+        };
+
+        if let Some(node) = node {
+            let res = if d.attr_id == AttributeId::Fill || d.attr_id == AttributeId::Stroke {
+                d.node.set_attribute_checked((d.attr_id, (node.clone(), d.fallback)))
+            } else {
+                d.node.set_attribute_checked((d.attr_id, node.clone()))
+            };
+
+            match res {
+                Ok(_) => continue,
+                Err(_) => {
+                    warn!("Failed to link an imported element '{}'.", node.id());
+                }
+            }
+        }
+
+        let av = if d.attr_id == AttributeId::Href {
+            AttributeValue::ExternalLink(d.iri)
+        } else {
+            AttributeValue::ExternalFuncLink(d.iri, d.fallback)
+        };
+        d.node.set_attribute((d.attr_id, av));
+    }
+}
+
+// Copies an element from the external document into the `defs` element.
+//
+// Each external document has its own importer, so elements shared
+// by different references are imported only once.
+fn import_element<F>(
+    doc: &mut Document,
+    iri: &ExternalIri,
+    resolver: &F,
+    docs: &mut HashMap<String, Option<Document>>,
+    importers: &mut HashMap<String, Importer>,
+) -> Option<Node>
+    where F: Fn(&str) -> Option<Document> + ?Sized
+{
+    let ext_doc = docs.entry(iri.path.clone()).or_insert_with(|| {
+        let ext_doc = resolver(&iri.path);
+        if ext_doc.is_none() {
+            warn!("Failed to load an external document '{}'.", iri.path);
+        }
+
+        ext_doc
+    });
+
+    let ext_doc = ext_doc.as_ref()?;
+    let ext_node = match ext_doc.node_by_id(&iri.id) {
+        Some(node) => node,
+        None => {
+            warn!("Element '{}' not found in '{}'.", iri.id, iri.path);
+            return None;
+        }
+    };
+
+    importers.entry(iri.path.clone())
+        .or_insert_with(Importer::new)
+        .import_element(doc, &ext_node)
+}
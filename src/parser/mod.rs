@@ -11,13 +11,22 @@ use svgtypes::{
 
 use super::*;
 
+mod color;
+mod external;
+mod options;
 mod text;
 
+pub use self::options::{
+    ExternalResolver,
+    ParseOptions,
+};
+
 
 struct Link {
     attr_id: AttributeId,
     iri: String,
     fallback: Option<PaintFallback>,
+    fallback_alpha: f64,
     node: Node,
 }
 
@@ -26,27 +35,53 @@ struct Links {
     list: Vec<Link>,
 }
 
+/// Parser state shared between all nodes.
+struct ParserData<'a> {
+    opt: &'a ParseOptions,
+    links: Links,
+    /// Links to elements of other documents.
+    external_links: Vec<external::ExternalLink>,
+    /// Alpha of the colors of the current element.
+    ///
+    /// Will be moved into the corresponding `*-opacity` attributes
+    /// after all the element attributes, CSS rules and `style` are parsed.
+    alpha: Vec<(AttributeId, f64)>,
+    /// Elements with a color alpha.
+    ///
+    /// Will be processed after all the links are resolved, because the alpha
+    /// affects inherited opacity of the descendants.
+    colors_alpha: Vec<(Node, AttributeId, f64)>,
+}
+
 impl Links {
     fn append(
         &mut self,
         id: AttributeId,
         iri: &str,
         fallback: Option<PaintFallback>,
+        fallback_alpha: f64,
         node: &Node,
     ) {
         self.list.push(Link {
             attr_id: id,
             iri: iri.to_string(),
             fallback,
+            fallback_alpha,
             node: node.clone(),
         });
     }
 }
 
-pub fn parse_svg(text: &str) -> Result<Document, ParserError> {
+pub fn parse_svg(text: &str, opt: &ParseOptions) -> Result<Document, ParserError> {
     let ro_doc = roxmltree::Document::parse(text)?;
 
-    let mut links = Links { list: Vec::new() };
+    let mut data = ParserData {
+        opt,
+        links: Links { list: Vec::new() },
+        external_links: Vec::new(),
+        alpha: Vec::new(),
+        colors_alpha: Vec::new(),
+    };
 
     let mut doc = Document::new();
     let root = doc.root();
@@ -55,7 +90,7 @@ pub fn parse_svg(text: &str) -> Result<Document, ParserError> {
     let style_sheet = resolve_css(&ro_doc);
 
     for child in ro_doc.root().children() {
-        process_node(&ro_doc, child, &style_sheet, &mut links, &mut doc, &mut parent)?;
+        process_node(&ro_doc, child, &style_sheet, &mut data, &mut doc, &mut parent)?;
     }
 
     // First element must be an 'svg' element.
@@ -67,7 +102,10 @@ pub fn parse_svg(text: &str) -> Result<Document, ParserError> {
     // is stored separately and will be processed later.
     doc.drain(root.clone(), |n| n.has_tag_name(ElementId::Style));
 
-    resolve_links(&doc, &mut links);
+    resolve_links(&doc, &mut data.links, &mut data.colors_alpha);
+    external::resolve_links(&mut doc, data.external_links, opt);
+
+    apply_colors_alpha(&data.colors_alpha);
 
     text::prepare_text(&mut doc);
 
@@ -78,7 +116,7 @@ fn process_node(
     ro_doc: &roxmltree::Document,
     xml_node: roxmltree::Node,
     style_sheet: &simplecss::StyleSheet,
-    links: &mut Links,
+    data: &mut ParserData<'_>,
     doc: &mut Document,
     parent: &mut Node,
 ) -> Result<(), ParserError> {
@@ -90,15 +128,15 @@ fn process_node(
 
             let tag_name = xml_node.tag_name();
             let local = tag_name.name();
-            let mut e = match ElementId::from_str(local) {
-                Some(eid) => {
-                    doc.create_element(eid)
-                }
+            let eid = match ElementId::from_str(local) {
+                Some(eid) => eid,
                 None => {
                     return Ok(());
                 }
             };
 
+            let mut e = doc.create_element(eid);
+
             for attr in xml_node.attributes() {
                 match attr.namespace() {
                     None |
@@ -108,9 +146,23 @@ fn process_node(
                     _ => continue,
                 }
 
+                // SVG 2 `href` takes precedence over `xlink:href`.
+                if attr.namespace() == Some("http://www.w3.org/1999/xlink")
+                    && attr.name() == "href"
+                    && xml_node.has_attribute("href")
+                {
+                    continue;
+                }
+
                 if let Some(aid) = AttributeId::from_str(attr.name()) {
-                    parse_svg_attribute(ro_doc, aid, attr.value(), attr.value_range().start,
-                                        &mut e, links)?;
+                    if data.opt.skip_invalid_attributes && !eid.allows_attribute(aid) {
+                        warn!("Attribute '{}' is not allowed on the '{}' element. Skipped.",
+                              aid, eid);
+                        continue;
+                    }
+
+                    parse_svg_attribute(ro_doc, doc, aid, attr.value(),
+                                        attr.value_range().start, &mut e, data)?;
                 }
             }
 
@@ -118,7 +170,7 @@ fn process_node(
                 if rule.selector.matches(&XmlNode(xml_node)) {
                     for declaration in &rule.declarations {
                         parse_css_attribute_value(
-                            ro_doc, declaration.name, declaration.value, &mut e, links,
+                            ro_doc, declaration.name, declaration.value, &mut e, data,
                         )?;
                     }
                 }
@@ -126,14 +178,18 @@ fn process_node(
 
             if let Some(attr) = xml_node.attribute_node("style") {
                 parse_style_attribute(&ro_doc, attr.value(), attr.value_range().start,
-                                      &mut e, links)?;
+                                      &mut e, data)?;
+            }
+
+            for (aid, alpha) in data.alpha.drain(..) {
+                data.colors_alpha.push((e.clone(), aid, alpha));
             }
 
             parent.append(e.clone());
 
             if xml_node.is_element() && xml_node.has_children() {
                 for child in xml_node.children() {
-                    process_node(ro_doc, child, style_sheet, links, doc, &mut e)?;
+                    process_node(ro_doc, child, style_sheet, data, doc, &mut e)?;
                 }
             }
         }
@@ -180,21 +236,31 @@ fn process_node(
 
 fn parse_svg_attribute<'a>(
     ro_doc: &roxmltree::Document,
+    doc: &Document,
     id: AttributeId,
     value: &'a str,
     value_pos: usize,
     node: &mut Node,
-    links: &mut Links,
+    data: &mut ParserData<'_>,
 ) -> Result<(), ParserError> {
     match id {
         AttributeId::Id => {
+            // Previous elements are already in the tree, so they are present in the ids index.
+            if !value.is_empty() && doc.node_by_id(value).is_some() {
+                if data.opt.fail_on_duplicated_ids {
+                    return Err(Error::DuplicatedId(value.to_string()).into());
+                }
+
+                warn!("Duplicated id '{}'. Links will be resolved to the first element.", value);
+            }
+
             node.set_id(value);
         }
         AttributeId::Style | AttributeId::Class => {
             // Ignore these attributes.
         }
         _ => {
-            parse_svg_attribute_value(ro_doc, id, value, value_pos, node, links)?;
+            parse_svg_attribute_value(ro_doc, id, value, value_pos, node, data)?;
         }
     }
 
@@ -207,9 +273,12 @@ fn parse_svg_attribute_value<'a>(
     value: &'a str,
     value_pos: usize,
     node: &mut Node,
-    links: &mut Links,
+    data: &mut ParserData<'_>,
 ) -> Result<(), ParserError> {
-    let av = _parse_svg_attribute_value(ro_doc, id, value, value_pos, node, links);
+    // A new value overrides the alpha of the previous one.
+    let prev_alpha = take_alpha(&mut data.alpha, id);
+
+    let av = _parse_svg_attribute_value(ro_doc, id, value, value_pos, node, data);
 
     match av {
         Ok(av) => {
@@ -223,6 +292,11 @@ fn parse_svg_attribute_value<'a>(
             }
         }
         Err(_) => {
+            // An invalid value is ignored, so the previous one is still in use.
+            if let Some(alpha) = prev_alpha {
+                data.alpha.push((id, alpha));
+            }
+
             warn!("Attribute '{}' has an invalid value: '{}'.", id, value);
         }
     }
@@ -235,17 +309,15 @@ fn parse_css_attribute_value<'a>(
     name: &str,
     value: &str,
     node: &mut Node,
-    links: &mut Links,
+    data: &mut ParserData<'_>,
 ) -> Result<(), ParserError> {
     if let Some(id) = AttributeId::from_str(name) {
-        // Parse only the presentation attributes.
-        // `transform` isn't a presentation attribute, but should be parsed anyway.
-        if !id.is_presentation() && id != AttributeId::Transform {
+        if !is_style_property(id, node) {
             return Ok(());
         }
 
         let mut parse_attr = |aid| {
-            parse_svg_attribute_value(ro_doc, aid, value, 0, node, links)
+            parse_svg_attribute_value(ro_doc, aid, value, 0, node, data)
         };
 
         if id == AttributeId::Marker {
@@ -286,7 +358,7 @@ fn _parse_svg_attribute_value<'a>(
     value: &'a str,
     value_pos: usize,
     node: &mut Node,
-    links: &mut Links,
+    data: &mut ParserData<'_>,
 ) -> Result<Option<AttributeValue>, svgtypes::Error> {
     use crate::AttributeId as AId;
 
@@ -300,10 +372,31 @@ fn _parse_svg_attribute_value<'a>(
             match Stream::from(value).parse_iri() {
                 Ok(link) => {
                     // Collect links for later processing.
-                    links.append(aid, link, None, node);
+                    data.links.append(aid, link, None, 1.0, node);
                     return Ok(None);
                 }
                 Err(_) => {
+                    // Hyperlinks and images are referencing whole documents.
+                    let is_document_link = match eid {
+                          ElementId::A
+                        | ElementId::FeImage
+                        | ElementId::Image
+                        | ElementId::Script => true,
+                        _ => false,
+                    };
+
+                    if !is_document_link {
+                        if let Some(iri) = external::parse_iri(value) {
+                            data.external_links.push(external::ExternalLink {
+                                attr_id: aid,
+                                iri,
+                                fallback: None,
+                                node: node.clone(),
+                            });
+                            return Ok(None);
+                        }
+                    }
+
                     return Ok(Some(AttributeValue::String(value.to_string())));
                 }
             }
@@ -330,10 +423,11 @@ fn _parse_svg_attribute_value<'a>(
         | AId::R
         | AId::Rx | AId::Ry
         | AId::Cx | AId::Cy
-        | AId::Fx | AId::Fy
+        | AId::Fx | AId::Fy | AId::Fr
         | AId::RefX | AId::RefY
         | AId::Width | AId::Height
         | AId::MarkerWidth | AId::MarkerHeight
+        | AId::Pitch
         | AId::StartOffset => {
             AttributeValue::Length(Length::from_str(value)?)
         }
@@ -367,7 +461,8 @@ fn _parse_svg_attribute_value<'a>(
         | AId::FillOpacity
         | AId::FloodOpacity
         | AId::StrokeOpacity
-        | AId::StopOpacity => {
+        | AId::StopOpacity
+        | AId::SolidOpacity => {
             match value {
                 "inherit" => AttributeValue::Inherit,
                 _ => {
@@ -405,34 +500,12 @@ fn _parse_svg_attribute_value<'a>(
                 | ElementId::AnimateMotion
                 | ElementId::AnimateTransform
                 => AttributeValue::String(value.to_string()),
-                _ => {
-                    match Paint::from_str(value)? {
-                        Paint::None => AttributeValue::None,
-                        Paint::Inherit => AttributeValue::Inherit,
-                        Paint::CurrentColor => AttributeValue::CurrentColor,
-                        Paint::Color(color) => AttributeValue::Color(color),
-                        Paint::FuncIRI(link, fallback) => {
-                            // Collect links for later processing.
-                            links.append(aid, link, fallback, node);
-                            return Ok(None);
-                        }
-                    }
-                }
+                _ => return parse_paint(aid, value, node, data),
             }
         }
 
         AId::Stroke => {
-            match Paint::from_str(value)? {
-                Paint::None => AttributeValue::None,
-                Paint::Inherit => AttributeValue::Inherit,
-                Paint::CurrentColor => AttributeValue::CurrentColor,
-                Paint::Color(color) => AttributeValue::Color(color),
-                Paint::FuncIRI(link, fallback) => {
-                    // Collect links for later processing.
-                    links.append(aid, link, fallback, node);
-                    return Ok(None);
-                }
-            }
+            return parse_paint(aid, value, node, data);
         }
 
           AId::ClipPath
@@ -446,10 +519,20 @@ fn _parse_svg_attribute_value<'a>(
                 "none" => AttributeValue::None,
                 "inherit" => AttributeValue::Inherit,
                 _ => {
+                    if let Some((iri, _)) = external::parse_func_iri(value) {
+                        data.external_links.push(external::ExternalLink {
+                            attr_id: aid,
+                            iri,
+                            fallback: None,
+                            node: node.clone(),
+                        });
+                        return Ok(None);
+                    }
+
                     let mut s = Stream::from(value);
                     let link = s.parse_func_iri()?;
                     // collect links for later processing
-                    links.append(aid, link, None, node);
+                    data.links.append(aid, link, None, 1.0, node);
                     return Ok(None);
                 }
             }
@@ -458,17 +541,18 @@ fn _parse_svg_attribute_value<'a>(
         AId::Color => {
             match value {
                 "inherit" => AttributeValue::Inherit,
-                _ => AttributeValue::Color(Color::from_str(value)?),
+                _ => parse_color(aid, value, data)?,
             }
         }
 
           AId::LightingColor
         | AId::FloodColor
-        | AId::StopColor => {
+        | AId::StopColor
+        | AId::SolidColor => {
             match value {
                 "inherit" => AttributeValue::Inherit,
                 "currentColor" => AttributeValue::CurrentColor,
-                _ => AttributeValue::Color(Color::from_str(value)?),
+                _ => parse_color(aid, value, data)?,
             }
         }
 
@@ -534,8 +618,7 @@ fn _parse_svg_attribute_value<'a>(
             }
         }
 
-          AId::Display
-        | AId::PointerEvents
+          AId::PointerEvents
         | AId::TextDecoration => {
             match value {
                 "none" => AttributeValue::None,
@@ -544,30 +627,20 @@ fn _parse_svg_attribute_value<'a>(
             }
         }
 
-          AId::ClipRule
-        | AId::ColorInterpolation
+          AId::ColorInterpolation
         | AId::ColorInterpolationFilters
         | AId::ColorProfile
         | AId::ColorRendering
         | AId::Direction
         | AId::DominantBaseline
         | AId::EnableBackground
-        | AId::FillRule
         | AId::FontFamily
-        | AId::FontStretch
-        | AId::FontStyle
-        | AId::FontVariant
-        | AId::FontWeight
         | AId::ImageRendering
         | AId::Kerning
         | AId::Overflow
         | AId::ShapeRendering
-        | AId::StrokeLinecap
-        | AId::StrokeLinejoin
-        | AId::TextAnchor
         | AId::TextRendering
         | AId::UnicodeBidi
-        | AId::Visibility
         | AId::WritingMode => {
             match value {
                 "inherit" => AttributeValue::Inherit,
@@ -575,6 +648,22 @@ fn _parse_svg_attribute_value<'a>(
             }
         }
 
+          AId::ClipRule
+        | AId::FillRule => parse_keyword::<FillRule>(value)?,
+        AId::Display => parse_keyword::<DisplayMode>(value)?,
+        AId::FontStretch => parse_keyword::<FontStretch>(value)?,
+        AId::FontStyle => parse_keyword::<FontStyle>(value)?,
+        AId::FontVariant => parse_keyword::<FontVariant>(value)?,
+        AId::FontWeight => parse_keyword::<FontWeight>(value)?,
+        AId::StrokeLinecap => parse_keyword::<LineCap>(value)?,
+        AId::StrokeLinejoin => parse_keyword::<LineJoin>(value)?,
+        AId::TextAnchor => parse_keyword::<TextAnchor>(value)?,
+        AId::Visibility => parse_keyword::<Visibility>(value)?,
+        AId::MixBlendMode => parse_keyword::<BlendMode>(value)?,
+        AId::Isolation => parse_keyword::<Isolation>(value)?,
+        AId::VectorEffect => parse_keyword::<VectorEffect>(value)?,
+        AId::PaintOrder => parse_keyword::<PaintOrder>(value)?,
+
           AId::LetterSpacing
         | AId::WordSpacing => {
               match value {
@@ -641,19 +730,213 @@ fn parse_number(value: &str) -> Result<f64, svgtypes::Error> {
     Ok(n)
 }
 
+fn parse_paint(
+    aid: AttributeId,
+    value: &str,
+    node: &Node,
+    data: &mut ParserData<'_>,
+) -> Result<Option<AttributeValue>, svgtypes::Error> {
+    if let Some((iri, fallback)) = external::parse_paint(value) {
+        data.external_links.push(external::ExternalLink {
+            attr_id: aid,
+            iri,
+            fallback,
+            node: node.clone(),
+        });
+        return Ok(None);
+    }
+
+    let (paint, alpha) = color::parse_paint(value)?;
+    let av = match paint {
+        Paint::None => AttributeValue::None,
+        Paint::Inherit => AttributeValue::Inherit,
+        Paint::CurrentColor => AttributeValue::CurrentColor,
+        Paint::Color(color) => {
+            push_alpha(&mut data.alpha, aid, alpha);
+            AttributeValue::Color(color)
+        }
+        Paint::FuncIRI(link, fallback) => {
+            // Collect links for later processing.
+            data.links.append(aid, link, fallback, alpha, node);
+            return Ok(None);
+        }
+    };
+
+    Ok(Some(av))
+}
+
+fn parse_color(
+    aid: AttributeId,
+    value: &str,
+    data: &mut ParserData<'_>,
+) -> Result<AttributeValue, svgtypes::Error> {
+    let (color, alpha) = color::parse_color(value)?;
+    push_alpha(&mut data.alpha, aid, alpha);
+    Ok(AttributeValue::Color(color))
+}
+
+fn push_alpha(list: &mut Vec<(AttributeId, f64)>, aid: AttributeId, alpha: f64) {
+    if alpha < 1.0 {
+        list.push((aid, alpha));
+    }
+}
+
+fn take_alpha(list: &mut Vec<(AttributeId, f64)>, aid: AttributeId) -> Option<f64> {
+    let idx = list.iter().position(|&(id, _)| id == aid)?;
+    Some(list.remove(idx).1)
+}
+
+// Returns an opacity attribute that corresponds to the color attribute.
+fn opacity_attribute(aid: AttributeId) -> Option<AttributeId> {
+    match aid {
+        AttributeId::Fill => Some(AttributeId::FillOpacity),
+        AttributeId::Stroke => Some(AttributeId::StrokeOpacity),
+        AttributeId::StopColor => Some(AttributeId::StopOpacity),
+        AttributeId::FloodColor => Some(AttributeId::FloodOpacity),
+        AttributeId::SolidColor => Some(AttributeId::SolidOpacity),
+        _ => None,
+    }
+}
+
+// SVG 1.1 colors doesn't have an alpha channel,
+// so we have to move it into the corresponding opacity attribute.
+//
+// `fill-opacity` and `stroke-opacity` are inheritable, unlike the color alpha,
+// so descendants that override the color must keep the original opacity
+// and descendants with an own opacity must inherit the alpha.
+//
+// All the new values are calculated from the original ones before applying.
+fn apply_colors_alpha(list: &[(Node, AttributeId, f64)]) {
+    let mut values = Vec::new();
+
+    for &(ref node, aid, alpha) in list {
+        let opacity_aid = match opacity_attribute(aid) {
+            Some(id) => id,
+            None => {
+                warn!("Attribute '{}' cannot have an alpha. Alpha ignored.", aid);
+                continue;
+            }
+        };
+
+        let opacity = match node.attributes().get_value(opacity_aid) {
+            Some(&AttributeValue::Number(n)) => n,
+            None if opacity_aid.is_inheritable() => inherited_opacity(node, opacity_aid),
+            None => 1.0,
+            Some(_) => {
+                warn!("Attribute '{}' cannot be combined with a color alpha. Alpha ignored.",
+                      opacity_aid);
+                continue;
+            }
+        };
+
+        values.push((node.clone(), opacity_aid, opacity * alpha));
+
+        if opacity_aid.is_inheritable() {
+            propagate_color_alpha(node, aid, opacity_aid, alpha, list, &mut values);
+        }
+    }
+
+    for (mut node, opacity_aid, opacity) in values {
+        node.set_attribute((opacity_aid, opacity));
+    }
+}
+
+fn propagate_color_alpha(
+    node: &Node,
+    aid: AttributeId,
+    opacity_aid: AttributeId,
+    alpha: f64,
+    list: &[(Node, AttributeId, f64)],
+    values: &mut Vec<(Node, AttributeId, f64)>,
+) {
+    for child in node.children().filter(|n| n.is_element()) {
+        let has_color = match child.attributes().get_value(aid) {
+            Some(AttributeValue::Inherit) | None => false,
+            Some(_) => true,
+        };
+
+        let opacity = match child.attributes().get_value(opacity_aid) {
+            Some(&AttributeValue::Number(n)) => Some(n),
+            _ => None,
+        };
+
+        if has_color {
+            // Elements with an own alpha are processed separately.
+            let has_alpha = list.iter().any(|(n, id, _)| *n == child && *id == aid);
+            if !has_alpha && !child.has_attribute(opacity_aid) {
+                values.push((child.clone(), opacity_aid, inherited_opacity(&child, opacity_aid)));
+            }
+        } else {
+            if let Some(opacity) = opacity {
+                values.push((child.clone(), opacity_aid, opacity * alpha));
+            }
+
+            propagate_color_alpha(&child, aid, opacity_aid, alpha, list, values);
+        }
+    }
+}
+
+// Returns an original opacity inherited by the element.
+fn inherited_opacity(node: &Node, opacity_aid: AttributeId) -> f64 {
+    for n in node.ancestors().skip(1) {
+        if let Some(&AttributeValue::Number(n)) = n.attributes().get_value(opacity_aid) {
+            return n;
+        }
+    }
+
+    1.0
+}
+
+// Parse only the presentation attributes and CSS properties without one.
+// `transform` isn't a presentation attribute, but should be parsed anyway.
+fn is_style_property(id: AttributeId, node: &Node) -> bool {
+    id.is_presentation() || id.is_style_only() || id == AttributeId::Transform
+        || is_geometry_property(id, node)
+}
+
+// SVG 2 turned the element position and size into CSS properties,
+// but only for elements that are establishing a viewport or a rectangle.
+fn is_geometry_property(id: AttributeId, node: &Node) -> bool {
+    match id {
+          AttributeId::X
+        | AttributeId::Y
+        | AttributeId::Width
+        | AttributeId::Height => {}
+        _ => return false,
+    }
+
+    match node.tag_id() {
+          Some(ElementId::ForeignObject)
+        | Some(ElementId::Image)
+        | Some(ElementId::Rect)
+        | Some(ElementId::Svg)
+        | Some(ElementId::Symbol)
+        | Some(ElementId::Use) => true,
+        _ => false,
+    }
+}
+
+// Parses a keyword-based attribute value. Unknown keywords are rejected.
+fn parse_keyword<T>(value: &str) -> Result<AttributeValue, svgtypes::Error>
+    where T: FromStr<Err = svgtypes::Error>, AttributeValue: From<T>
+{
+    match value {
+        "inherit" => Ok(AttributeValue::Inherit),
+        _ => Ok(T::from_str(value)?.into()),
+    }
+}
+
 fn parse_style_attribute(
     ro_doc: &roxmltree::Document,
     value: &str,
     value_pos: usize,
     node: &mut Node,
-    links: &mut Links,
+    data: &mut ParserData<'_>,
 ) -> Result<(), ParserError> {
     for declaration in simplecss::DeclarationTokenizer::from(value) {
         if let Some(id) = AttributeId::from_str(declaration.name) {
-            // Parse only the presentation attributes.
-            // `transform` isn't a presentation attribute, but should be parsed anyway.
-            if id.is_presentation() || id == AttributeId::Transform {
-                parse_svg_attribute_value(ro_doc, id, declaration.value, value_pos, node, links)?;
+            if is_style_property(id, node) {
+                parse_svg_attribute_value(ro_doc, id, declaration.value, value_pos, node, data)?;
             }
         }
     }
@@ -661,9 +944,13 @@ fn parse_style_attribute(
     Ok(())
 }
 
-fn resolve_links(doc: &Document, links: &mut Links) {
+fn resolve_links(
+    doc: &Document,
+    links: &mut Links,
+    colors_alpha: &mut Vec<(Node, AttributeId, f64)>,
+) {
     for d in &mut links.list {
-        match doc.root().descendants().find(|n| *n.id() == d.iri) {
+        match doc.node_by_id(&d.iri) {
             Some(node) => {
                 let res = if d.attr_id == AttributeId::Fill || d.attr_id == AttributeId::Stroke {
                     d.node.set_attribute_checked((d.attr_id, (node.clone(), d.fallback)))
@@ -683,6 +970,9 @@ fn resolve_links(doc: &Document, links: &mut Links) {
                         let attr = Attribute::from((d.attr_id, node.clone()));
                         warn!("Crosslink detected. Attribute {} ignored.", attr);
                     }
+                    Err(Error::DuplicatedId(_))
+                    | Err(Error::NodeBorrowed)
+                    | Err(Error::RecursiveInsertion) => unreachable!(),
                 }
             }
             None => {
@@ -706,6 +996,10 @@ fn resolve_links(doc: &Document, links: &mut Links) {
                 };
 
                 d.node.set_attribute((d.attr_id, av));
+
+                if d.fallback_alpha < 1.0 {
+                    colors_alpha.push((d.node.clone(), d.attr_id, d.fallback_alpha));
+                }
             }
         }
     }
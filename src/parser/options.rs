@@ -0,0 +1,80 @@
+use std::fmt;
+
+use crate::Document;
+
+/// A callback that loads an external document by its path.
+pub type ExternalResolver = Box<dyn Fn(&str) -> Option<Document>>;
+
+/// Options that defines SVG parsing.
+#[derive(Default)]
+pub struct ParseOptions {
+    /// Skip attributes that are not allowed on the element by the SVG specification.
+    ///
+    /// CSS properties from the `style` element and attribute are not affected.
+    ///
+    /// # Examples
+    ///
+    /// Before:
+    ///
+    /// ```text
+    /// <rect x="10" r="5" cx="20"/>
+    /// ```
+    ///
+    /// After:
+    ///
+    /// ```text
+    /// <rect x="10"/>
+    /// ```
+    ///
+    /// Default: disabled
+    pub skip_invalid_attributes: bool,
+
+    /// Return an error on duplicated element ids.
+    ///
+    /// Otherwise, a warning will be printed and all links will be resolved
+    /// to the first element with such id.
+    ///
+    /// Duplicated ids can be fixed later using
+    /// [`Document::make_ids_unique`](struct.Document.html#method.make_ids_unique).
+    ///
+    /// Default: disabled
+    pub fail_on_duplicated_ids: bool,
+
+    /// A callback that loads documents referenced via an external IRI,
+    /// like `xlink:href="sprite.svg#icon"` or `fill="url(sprite.svg#grad)"`.
+    ///
+    /// The callback receives a path as is and should return a parsed document.
+    /// The referenced element will be imported into the `defs` element
+    /// using [`Document::import_node`](struct.Document.html#method.import_node)
+    /// and the attribute will be linked to it.
+    ///
+    /// References that cannot be resolved are stored as
+    /// `AttributeValue::ExternalLink` and `AttributeValue::ExternalFuncLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use svgdom::{Document, ParseOptions};
+    ///
+    /// let opt = ParseOptions {
+    ///     external_resolver: Some(Box::new(|path| {
+    ///         let text = std::fs::read_to_string(path).ok()?;
+    ///         Document::from_str(&text).ok()
+    ///     })),
+    ///     ..ParseOptions::default()
+    /// };
+    /// ```
+    ///
+    /// Default: `None`
+    pub external_resolver: Option<ExternalResolver>,
+}
+
+impl fmt::Debug for ParseOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParseOptions")
+            .field("skip_invalid_attributes", &self.skip_invalid_attributes)
+            .field("fail_on_duplicated_ids", &self.fail_on_duplicated_ids)
+            .field("external_resolver", &self.external_resolver.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,365 @@
+// Path normalization.
+
+use std::f64;
+
+use crate::{
+    Path,
+    PathSegment,
+    Transform,
+};
+use crate::geom::CenterArc;
+
+// Prevents an endless subdivision on a very small tolerance.
+const MAX_ARC_SEGMENTS: usize = 1024;
+
+/// Options that defines path normalization.
+///
+/// Relative segments are always converted into absolute one,
+/// since all other steps depend on it.
+#[derive(Clone, Copy, Debug)]
+pub struct NormalizeOptions {
+    /// Replace shorthand segments with their full equivalents.
+    ///
+    /// `H` and `V` will be converted into `L`, `S` into `C` and `T` into `Q`.
+    ///
+    /// # Examples
+    ///
+    /// Before:
+    ///
+    /// ```text
+    /// M 10 20 H 30 V 40 S 50 60 70 80
+    /// ```
+    ///
+    /// After:
+    ///
+    /// ```text
+    /// M 10 20 L 30 20 L 30 40 C 30 40 50 60 70 80
+    /// ```
+    ///
+    /// Default: enabled
+    pub expand_shorthands: bool,
+
+    /// Convert quadratic curves into cubic one.
+    ///
+    /// This conversion is lossless.
+    ///
+    /// # Examples
+    ///
+    /// Before:
+    ///
+    /// ```text
+    /// M 0 0 Q 30 30 60 0
+    /// ```
+    ///
+    /// After:
+    ///
+    /// ```text
+    /// M 0 0 C 20 20 40 20 60 0
+    /// ```
+    ///
+    /// Default: enabled
+    pub quads_to_cubics: bool,
+
+    /// Convert elliptical arcs into cubic curves.
+    ///
+    /// Each arc will be split into segments up to 90 degrees,
+    /// or smaller ones when `arc_tolerance` is not satisfied.
+    /// Arcs with a zero radius will be converted into lines.
+    ///
+    /// Default: enabled
+    pub arcs_to_cubics: bool,
+
+    /// The maximum allowed distance between an arc and its approximation in user units.
+    ///
+    /// Non-positive values disable the check.
+    ///
+    /// Default: 0.01
+    pub arc_tolerance: f64,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        NormalizeOptions {
+            expand_shorthands: true,
+            quads_to_cubics: true,
+            arcs_to_cubics: true,
+            arc_tolerance: 0.01,
+        }
+    }
+}
+
+/// Path normalization.
+pub trait PathNormalize {
+    /// Normalizes the path according to the options.
+    ///
+    /// # Examples
+    /// ```
+    /// use svgdom::{NormalizeOptions, Path, PathNormalize};
+    ///
+    /// let mut path: Path = "m 10 20 h 20 q 15 15 30 0".parse().unwrap();
+    /// path.normalize(&NormalizeOptions::default());
+    /// assert_eq!(path.to_string(), "M 10 20 L 30 20 C 40 30 50 30 60 20");
+    /// ```
+    fn normalize(&mut self, opt: &NormalizeOptions);
+}
+
+impl PathNormalize for Path {
+    fn normalize(&mut self, opt: &NormalizeOptions) {
+        self.conv_to_absolute();
+
+        let mut path = Path::with_capacity(self.len());
+        let (mut px, mut py) = (0.0, 0.0);
+        let (mut mx, mut my) = (0.0, 0.0);
+        // The last control points of the previous curves, used by smooth curves.
+        let mut prev_cubic: Option<(f64, f64)> = None;
+        let mut prev_quad: Option<(f64, f64)> = None;
+
+        for seg in self.iter() {
+            let mut cubic = None;
+            let mut quad = None;
+
+            // Smooth curves can be preserved only when the previous segment
+            // was not converted into a curve of a different type.
+            let prev_is_cubic = match path.last() {
+                Some(&PathSegment::CurveTo { .. }) | Some(&PathSegment::SmoothCurveTo { .. }) => true,
+                _ => false,
+            };
+
+            match *seg {
+                PathSegment::MoveTo { x, y, .. } => {
+                    path.push_move_to(x, y);
+                    mx = x;
+                    my = y;
+                }
+                PathSegment::LineTo { x, y, .. } => {
+                    path.push_line_to(x, y);
+                }
+                PathSegment::HorizontalLineTo { x, .. } => {
+                    if opt.expand_shorthands {
+                        path.push_line_to(x, py);
+                    } else {
+                        path.push_hline_to(x);
+                    }
+                }
+                PathSegment::VerticalLineTo { y, .. } => {
+                    if opt.expand_shorthands {
+                        path.push_line_to(px, y);
+                    } else {
+                        path.push_vline_to(y);
+                    }
+                }
+                PathSegment::CurveTo { x1, y1, x2, y2, x, y, .. } => {
+                    path.push_curve_to(x1, y1, x2, y2, x, y);
+                    cubic = Some((x2, y2));
+                }
+                PathSegment::SmoothCurveTo { x2, y2, x, y, .. } => {
+                    if opt.expand_shorthands || prev_cubic.is_some() != prev_is_cubic {
+                        let (x1, y1) = reflect(prev_cubic, px, py);
+                        path.push_curve_to(x1, y1, x2, y2, x, y);
+                    } else {
+                        path.push_smooth_curve_to(x2, y2, x, y);
+                    }
+                    cubic = Some((x2, y2));
+                }
+                PathSegment::Quadratic { x1, y1, x, y, .. } => {
+                    push_quad(&mut path, opt, (px, py), (x1, y1), (x, y));
+                    quad = Some((x1, y1));
+                }
+                PathSegment::SmoothQuadratic { x, y, .. } => {
+                    let (x1, y1) = reflect(prev_quad, px, py);
+                    if opt.expand_shorthands || opt.quads_to_cubics {
+                        push_quad(&mut path, opt, (px, py), (x1, y1), (x, y));
+                    } else {
+                        path.push_smooth_quad_to(x, y);
+                    }
+                    quad = Some((x1, y1));
+                }
+                PathSegment::EllipticalArc { rx, ry, x_axis_rotation, large_arc, sweep, x, y, .. } => {
+                    if opt.arcs_to_cubics {
+                        let arc = CenterArc::new((px, py), rx, ry, x_axis_rotation,
+                                                 large_arc, sweep, (x, y));
+                        match arc {
+                            Some(arc) => push_arc(&mut path, &arc, opt.arc_tolerance, (x, y)),
+                            None if (px, py) != (x, y) => path.push_line_to(x, y),
+                            // An arc with equal endpoints is omitted.
+                            None => {}
+                        }
+                    } else {
+                        path.push_arc_to(rx, ry, x_axis_rotation, large_arc, sweep, x, y);
+                    }
+                }
+                PathSegment::ClosePath { .. } => {
+                    path.push_close_path();
+                    px = mx;
+                    py = my;
+                }
+            }
+
+            if let Some(x) = seg.x() {
+                px = x;
+            }
+
+            if let Some(y) = seg.y() {
+                py = y;
+            }
+
+            prev_cubic = cubic;
+            prev_quad = quad;
+        }
+
+        *self = path;
+    }
+}
+
+// Applies the transform to the path data.
+//
+// Horizontal and vertical lines are converted into regular lines,
+// since they may not be preserved after a rotation.
+pub fn transform_path(path: &mut Path, ts: &Transform) {
+    path.conv_to_absolute();
+
+    let (mut px, mut py) = (0.0, 0.0);
+    let (mut mx, mut my) = (0.0, 0.0);
+    for seg in path.iter_mut() {
+        if let PathSegment::MoveTo { x, y, .. } = *seg {
+            mx = x;
+            my = y;
+        }
+
+        if let Some(x) = seg.x() {
+            px = x;
+        }
+
+        if let Some(y) = seg.y() {
+            py = y;
+        }
+
+        if let PathSegment::ClosePath { .. } = *seg {
+            px = mx;
+            py = my;
+        }
+
+        match *seg {
+            PathSegment::HorizontalLineTo { .. } | PathSegment::VerticalLineTo { .. } => {
+                let (x, y) = ts.apply(px, py);
+                *seg = PathSegment::LineTo { abs: true, x, y };
+            }
+            PathSegment::MoveTo { ref mut x, ref mut y, .. }
+            | PathSegment::LineTo { ref mut x, ref mut y, .. }
+            | PathSegment::SmoothQuadratic { ref mut x, ref mut y, .. } => {
+                ts.apply_to(x, y);
+            }
+            PathSegment::CurveTo { ref mut x1, ref mut y1, ref mut x2, ref mut y2,
+                                   ref mut x, ref mut y, .. } => {
+                ts.apply_to(x1, y1);
+                ts.apply_to(x2, y2);
+                ts.apply_to(x, y);
+            }
+              PathSegment::SmoothCurveTo { x2: ref mut x1, y2: ref mut y1, ref mut x, ref mut y, .. }
+            | PathSegment::Quadratic { ref mut x1, ref mut y1, ref mut x, ref mut y, .. } => {
+                ts.apply_to(x1, y1);
+                ts.apply_to(x, y);
+            }
+            PathSegment::EllipticalArc { ref mut rx, ref mut ry, ref mut x_axis_rotation,
+                                         ref mut sweep, ref mut x, ref mut y, .. } => {
+                if *rx != 0.0 && *ry != 0.0 {
+                    let (new_rx, new_ry, angle) = transform_arc_radii(*rx, *ry, *x_axis_rotation, ts);
+                    *rx = new_rx;
+                    *ry = new_ry;
+                    *x_axis_rotation = angle;
+
+                    // Mirroring changes the arc direction.
+                    if ts.a * ts.d - ts.b * ts.c < 0.0 {
+                        *sweep = !*sweep;
+                    }
+                }
+
+                ts.apply_to(x, y);
+            }
+            PathSegment::ClosePath { .. } => {}
+        }
+    }
+}
+
+// Calculates the ellipse axes after the transformation using the singular value decomposition.
+fn transform_arc_radii(rx: f64, ry: f64, x_axis_rotation: f64, ts: &Transform) -> (f64, f64, f64) {
+    let (sin, cos) = x_axis_rotation.to_radians().sin_cos();
+
+    // An ellipse matrix: `ts * rotate * scale(rx, ry)`.
+    let m00 = (ts.a * cos + ts.c * sin) * rx;
+    let m01 = (-ts.a * sin + ts.c * cos) * ry;
+    let m10 = (ts.b * cos + ts.d * sin) * rx;
+    let m11 = (-ts.b * sin + ts.d * cos) * ry;
+
+    let e = (m00 + m11) / 2.0;
+    let f = (m00 - m11) / 2.0;
+    let g = (m10 + m01) / 2.0;
+    let h = (m10 - m01) / 2.0;
+
+    let q = (e * e + h * h).sqrt();
+    let r = (f * f + g * g).sqrt();
+    let angle = (g.atan2(f) + h.atan2(e)) / 2.0;
+
+    (q + r, (q - r).abs(), angle.to_degrees())
+}
+
+fn reflect(p: Option<(f64, f64)>, px: f64, py: f64) -> (f64, f64) {
+    match p {
+        Some((x, y)) => (px * 2.0 - x, py * 2.0 - y),
+        None => (px, py),
+    }
+}
+
+fn push_quad(path: &mut Path, opt: &NormalizeOptions, p0: (f64, f64), p1: (f64, f64), p2: (f64, f64)) {
+    if opt.quads_to_cubics {
+        let x1 = p0.0 + 2.0 / 3.0 * (p1.0 - p0.0);
+        let y1 = p0.1 + 2.0 / 3.0 * (p1.1 - p0.1);
+        let x2 = p2.0 + 2.0 / 3.0 * (p1.0 - p2.0);
+        let y2 = p2.1 + 2.0 / 3.0 * (p1.1 - p2.1);
+        path.push_curve_to(x1, y1, x2, y2, p2.0, p2.1);
+    } else {
+        path.push_quad_to(p1.0, p1.1, p2.0, p2.1);
+    }
+}
+
+fn push_arc(path: &mut Path, arc: &CenterArc, tolerance: f64, end: (f64, f64)) {
+    let count = arc_segments_count(arc, tolerance);
+    let step = arc.dtheta / count as f64;
+    // The length of the control point tangent for a unit circle.
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+    let mut theta = arc.theta1;
+    for i in 0..count {
+        let (sin1, cos1) = theta.sin_cos();
+        theta += step;
+        let (sin2, cos2) = theta.sin_cos();
+
+        let (x1, y1) = arc.map(cos1 - k * sin1, sin1 + k * cos1);
+        let (x2, y2) = arc.map(cos2 + k * sin2, sin2 - k * cos2);
+        // Use the exact end point to prevent an error accumulation.
+        let (x, y) = if i + 1 == count { end } else { arc.map(cos2, sin2) };
+
+        path.push_curve_to(x1, y1, x2, y2, x, y);
+    }
+}
+
+fn arc_segments_count(arc: &CenterArc, tolerance: f64) -> usize {
+    let sweep = arc.dtheta.abs();
+    let mut count = ((sweep / f64::consts::FRAC_PI_2).ceil() as usize).max(1);
+    if tolerance <= 0.0 {
+        return count;
+    }
+
+    // An approximate maximum error of a cubic curve for a circular arc.
+    let radius = arc.rx.max(arc.ry);
+    let error = |angle: f64| {
+        let a = angle / 4.0;
+        radius * 4.0 / 27.0 * a.sin().powi(6) / a.cos().powi(2)
+    };
+
+    while count < MAX_ARC_SEGMENTS && error(sweep / count as f64) > tolerance {
+        count += 1;
+    }
+
+    count
+}
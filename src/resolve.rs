@@ -0,0 +1,401 @@
+// Resolving of elements references.
+
+use log::warn;
+
+use crate::{
+    Align,
+    AspectRatio,
+    AttributeId as AId,
+    AttributeValue,
+    Document,
+    ElementId,
+    ElementType,
+    Length,
+    LengthUnit,
+    Node,
+    Transform,
+    ViewBox,
+};
+use crate::import;
+use crate::units::{
+    convert_length,
+    resolve_length,
+};
+
+// Prevents an exponential growth of the document
+// when `use` elements are referencing each other in a chain.
+const MAX_EXPANDED_NODES: usize = 100_000;
+
+/// Replaces all `use` elements with the referenced content.
+pub fn resolve_use(doc: &mut Document) -> usize {
+    let nodes: Vec<Node> = doc.root().descendants()
+        .filter(|n| n.has_tag_name(ElementId::Use))
+        .collect();
+
+    let mut limit = MAX_EXPANDED_NODES;
+    let mut count = 0;
+    for node in nodes {
+        // The node may be already removed along with its parent.
+        if !node.is_detached() {
+            count += resolve_use_impl(doc, node, &mut limit);
+        }
+    }
+
+    count
+}
+
+/// Replaces a `use` element with the referenced content.
+///
+/// Nested `use` elements inside the copied content are resolved too.
+///
+/// Returns the number of resolved `use` elements.
+pub fn resolve_use_node(doc: &mut Document, node: Node) -> usize {
+    let mut limit = MAX_EXPANDED_NODES;
+    resolve_use_impl(doc, node, &mut limit)
+}
+
+// `limit` is the number of nodes that still can be copied.
+fn resolve_use_impl(doc: &mut Document, mut node: Node, limit: &mut usize) -> usize {
+    let target = match use_target(&node) {
+        Some(target) => target,
+        None => return 0,
+    };
+
+    if is_recursive(&node, &target, &mut Vec::new()) {
+        warn!("Recursive 'use' detected. Element '{}' ignored.", target.id());
+        return 0;
+    }
+
+    let nodes_count = target.descendants().count();
+    if nodes_count > *limit {
+        warn!("Too many nodes were copied by 'use' elements. Element '{}' ignored.",
+              target.id());
+        return 0;
+    }
+    *limit -= nodes_count;
+
+    let x = resolve_length(&node, AId::X, 0.0);
+    let y = resolve_length(&node, AId::Y, 0.0);
+
+    let mut ts = match node.attributes().get_value(AId::Transform) {
+        Some(&AttributeValue::Transform(ts)) => ts,
+        _ => Transform::default(),
+    };
+    ts.translate(x, y);
+
+    let content = match target.tag_id() {
+        Some(ElementId::Symbol) | Some(ElementId::Svg) => {
+            flatten_viewport(doc, &node, &target)
+        }
+        _ => {
+            doc.copy_node_deep(target)
+        }
+    };
+
+    // The `use` element itself became a group, so its id and incoming links are preserved.
+    node.set_tag_name(ElementId::G);
+    for aid in &[AId::X, AId::Y, AId::Width, AId::Height, AId::Href, AId::Transform] {
+        node.remove_attribute(*aid);
+    }
+
+    if !ts.is_default() {
+        node.set_attribute((AId::Transform, ts));
+    }
+
+    node.append(content.clone());
+
+    let mut count = 1;
+    let nested: Vec<Node> = content.descendants()
+        .filter(|n| n.has_tag_name(ElementId::Use))
+        .collect();
+    for n in nested {
+        count += resolve_use_impl(doc, n, limit);
+    }
+
+    count
+}
+
+// Copies a `symbol` or an `svg` element as a group.
+//
+// The viewport position and `viewBox` are converted into a transform
+// and the viewport clipping into a `clipPath`.
+fn flatten_viewport(doc: &mut Document, node: &Node, target: &Node) -> Node {
+    // `width` and `height` of the `use` element override the viewport size.
+    let size = |aid| {
+        let n = if node.has_attribute(aid) { node } else { target };
+        let def = convert_length(Length::new(100.0, LengthUnit::Percent), node, aid);
+        resolve_length(n, aid, def)
+    };
+
+    let x = resolve_length(target, AId::X, 0.0);
+    let y = resolve_length(target, AId::Y, 0.0);
+    let width = size(AId::Width);
+    let height = size(AId::Height);
+
+    let view_box = match target.attributes().get_value(AId::ViewBox) {
+        Some(&AttributeValue::ViewBox(vb)) => Some(vb),
+        _ => None,
+    };
+
+    let is_empty = match view_box {
+        Some(vb) => !(vb.w > 0.0 && vb.h > 0.0),
+        None => false,
+    };
+
+    // A viewport with a zero size disables rendering, so the children are not copied.
+    let is_empty = is_empty || !(width > 0.0 && height > 0.0);
+    let mut group = if is_empty {
+        doc.copy_node(target.clone())
+    } else {
+        doc.copy_node_deep(target.clone())
+    };
+
+    group.set_tag_name(ElementId::G);
+    for aid in &[AId::X, AId::Y, AId::Width, AId::Height, AId::ViewBox,
+                 AId::PreserveAspectRatio, AId::Overflow, AId::RefX, AId::RefY] {
+        group.remove_attribute(*aid);
+    }
+
+    if is_empty {
+        return group;
+    }
+
+    let mut ts = Transform::new_translate(x, y);
+    if let Some(vb) = view_box {
+        let aspect = match target.attributes().get_value(AId::PreserveAspectRatio) {
+            Some(&AttributeValue::AspectRatio(aspect)) => aspect,
+            _ => AspectRatio::default(),
+        };
+
+        ts.append(&view_box_transform(vb, aspect, width, height));
+    }
+
+    let is_visible = match target.attributes().get_value(AId::Overflow) {
+        Some(AttributeValue::String(s)) => s == "visible" || s == "auto",
+        _ => false,
+    };
+
+    if !is_visible && group.has_children() {
+        // The clipping rectangle is the viewport mapped into the content coordinates.
+        let (sx, sy) = (ts.a, ts.d);
+        let clip_rect = ((x - ts.e) / sx, (y - ts.f) / sy, width / sx, height / sy);
+        if let Some(clip_path) = create_clip_path(doc, clip_rect) {
+            group.set_attribute((AId::ClipPath, clip_path));
+        }
+    }
+
+    if !ts.is_default() {
+        group.set_attribute((AId::Transform, ts));
+    }
+
+    group
+}
+
+// Calculates a transform that maps the `viewBox` into the viewport.
+fn view_box_transform(vb: ViewBox, aspect: AspectRatio, width: f64, height: f64) -> Transform {
+    let sx = width / vb.w;
+    let sy = height / vb.h;
+
+    if aspect.align == Align::None {
+        return Transform::new(sx, 0.0, 0.0, sy, -vb.x * sx, -vb.y * sy);
+    }
+
+    let s = if aspect.slice { sx.max(sy) } else { sx.min(sy) };
+
+    // Free space along each axis.
+    let dx = width - vb.w * s;
+    let dy = height - vb.h * s;
+
+    let (ax, ay) = match aspect.align {
+        Align::None | Align::XMinYMin => (0.0, 0.0),
+        Align::XMidYMin => (0.5, 0.0),
+        Align::XMaxYMin => (1.0, 0.0),
+        Align::XMinYMid => (0.0, 0.5),
+        Align::XMidYMid => (0.5, 0.5),
+        Align::XMaxYMid => (1.0, 0.5),
+        Align::XMinYMax => (0.0, 1.0),
+        Align::XMidYMax => (0.5, 1.0),
+        Align::XMaxYMax => (1.0, 1.0),
+    };
+
+    Transform::new(s, 0.0, 0.0, s, dx * ax - vb.x * s, dy * ay - vb.y * s)
+}
+
+// Creates a `clipPath` element with a rectangle inside the `defs` element.
+fn create_clip_path(doc: &mut Document, rect: (f64, f64, f64, f64)) -> Option<Node> {
+    let mut defs = import::defs_element(doc)?;
+
+    let mut idx = 1;
+    while doc.node_by_id(&format!("clipPath{}", idx)).is_some() {
+        idx += 1;
+    }
+
+    let mut clip_path = doc.create_element(ElementId::ClipPath);
+    clip_path.set_id(format!("clipPath{}", idx));
+    defs.append(clip_path.clone());
+
+    let mut rect_node = doc.create_element(ElementId::Rect);
+    rect_node.set_attribute((AId::X, rect.0));
+    rect_node.set_attribute((AId::Y, rect.1));
+    rect_node.set_attribute((AId::Width, rect.2));
+    rect_node.set_attribute((AId::Height, rect.3));
+    clip_path.append(rect_node);
+
+    Some(clip_path)
+}
+
+/// Copies attributes and children inherited via `xlink:href`
+/// into gradients and patterns and removes the `xlink:href` attribute.
+pub fn resolve_href_inheritance(doc: &mut Document) -> usize {
+    let nodes: Vec<Node> = doc.root().descendants()
+        .filter(|n| href_target(n).is_some())
+        .collect();
+
+    // Chains must be resolved using the original links,
+    // so all the changes are collected before applying.
+    let mut changes = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        let chain = href_chain(&node);
+
+        let mut attrs = Vec::new();
+        for aid in inheritable_attributes(&node) {
+            if node.has_attribute(*aid) {
+                continue;
+            }
+
+            let value = chain.iter().find_map(|n| {
+                if inheritable_attributes(n).contains(aid) {
+                    n.attributes().get_value(*aid).cloned()
+                } else {
+                    None
+                }
+            });
+
+            if let Some(value) = value {
+                attrs.push((*aid, value));
+            }
+        }
+
+        let children = if has_content(&node) {
+            None
+        } else {
+            chain.iter().find(|n| has_content(n)).cloned()
+        };
+
+        changes.push((node, attrs, children));
+    }
+
+    let count = changes.len();
+    for (mut node, attrs, children) in changes {
+        node.remove_attribute(AId::Href);
+
+        for (aid, value) in attrs {
+            node.set_attribute((aid, value));
+        }
+
+        if let Some(children) = children {
+            for child in children.children() {
+                let new_child = doc.copy_node_deep(child);
+                node.append(new_child);
+            }
+        }
+    }
+
+    count
+}
+
+fn href_target(node: &Node) -> Option<Node> {
+    match node.attributes().get_value(AId::Href) {
+        Some(AttributeValue::Link(link)) if is_same_kind(node, link) => Some(link.clone()),
+        _ => None,
+    }
+}
+
+// Gradients can reference only gradients and patterns only patterns.
+fn is_same_kind(node1: &Node, node2: &Node) -> bool {
+    if node1.is_gradient() {
+        node2.is_gradient()
+    } else {
+        node1.has_tag_name(ElementId::Pattern) && node2.has_tag_name(ElementId::Pattern)
+    }
+}
+
+// Returns all elements referenced by the node directly or indirectly.
+fn href_chain(node: &Node) -> Vec<Node> {
+    let mut chain: Vec<Node> = Vec::new();
+    let mut node = node.clone();
+    while let Some(link) = href_target(&node) {
+        // Prevent an infinite loop.
+        if chain.contains(&link) {
+            break;
+        }
+
+        chain.push(link.clone());
+        node = link;
+    }
+
+    chain
+}
+
+// Gradients can inherit attributes from any gradient, but geometry attributes
+// only from the same gradient type.
+fn inheritable_attributes(node: &Node) -> &'static [AId] {
+    match node.tag_id() {
+        Some(ElementId::LinearGradient) => &[
+            AId::GradientUnits, AId::GradientTransform, AId::SpreadMethod,
+            AId::X1, AId::Y1, AId::X2, AId::Y2,
+        ],
+        Some(ElementId::RadialGradient) => &[
+            AId::GradientUnits, AId::GradientTransform, AId::SpreadMethod,
+            AId::Cx, AId::Cy, AId::R, AId::Fx, AId::Fy, AId::Fr,
+        ],
+        Some(ElementId::Pattern) => &[
+            AId::PatternUnits, AId::PatternContentUnits, AId::PatternTransform,
+            AId::X, AId::Y, AId::Width, AId::Height, AId::ViewBox, AId::PreserveAspectRatio,
+        ],
+        _ => &[],
+    }
+}
+
+// Gradients inherit stops and patterns inherit children
+// only when they don't have their own.
+fn has_content(node: &Node) -> bool {
+    if node.is_gradient() {
+        node.children().any(|n| n.has_tag_name(ElementId::Stop))
+    } else {
+        node.children().any(|n| n.is_element())
+    }
+}
+
+fn use_target(node: &Node) -> Option<Node> {
+    if !node.has_tag_name(ElementId::Use) {
+        return None;
+    }
+
+    match node.attributes().get_value(AId::Href) {
+        Some(AttributeValue::Link(link)) => Some(link.clone()),
+        _ => None,
+    }
+}
+
+// Checks that an instantiation of the `target` will never end.
+//
+// This happens when the `target` contains the `use` element itself or when
+// references of the nested `use` elements are forming a cycle.
+fn is_recursive(node: &Node, target: &Node, path: &mut Vec<Node>) -> bool {
+    if node.ancestors().any(|n| n == *target) || path.contains(target) {
+        return true;
+    }
+
+    path.push(target.clone());
+    for n in target.descendants() {
+        if let Some(ref link) = use_target(&n) {
+            if is_recursive(node, link, path) {
+                return true;
+            }
+        }
+    }
+    path.pop();
+
+    false
+}
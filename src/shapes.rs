@@ -0,0 +1,166 @@
+// Conversion of basic shapes into paths.
+
+use crate::{
+    AttributeId as AId,
+    AttributeValue,
+    ElementId,
+    ElementType,
+    Node,
+    Path,
+};
+use crate::bbox::find_inherited;
+use crate::units::resolve_length;
+
+/// Converts a basic shape into an equivalent path data.
+///
+/// Returns `None` if the node is not a basic shape
+/// or if the shape is invalid and should not be rendered.
+pub fn shape_to_path(node: &Node) -> Option<Path> {
+    let mut path = Path::new();
+
+    match node.tag_id()? {
+        ElementId::Rect => {
+            let x = resolve_length(node, AId::X, 0.0);
+            let y = resolve_length(node, AId::Y, 0.0);
+            let w = resolve_length(node, AId::Width, 0.0);
+            let h = resolve_length(node, AId::Height, 0.0);
+            if !(w > 0.0 && h > 0.0) {
+                return None;
+            }
+
+            let (rx, ry) = resolve_rx_ry(node, w, h);
+            if rx > 0.0 && ry > 0.0 {
+                path.push_move_to(x + rx, y);
+                path.push_hline_to(x + w - rx);
+                path.push_arc_to(rx, ry, 0.0, false, true, x + w, y + ry);
+                path.push_vline_to(y + h - ry);
+                path.push_arc_to(rx, ry, 0.0, false, true, x + w - rx, y + h);
+                path.push_hline_to(x + rx);
+                path.push_arc_to(rx, ry, 0.0, false, true, x, y + h - ry);
+                path.push_vline_to(y + ry);
+                path.push_arc_to(rx, ry, 0.0, false, true, x + rx, y);
+            } else {
+                path.push_move_to(x, y);
+                path.push_hline_to(x + w);
+                path.push_vline_to(y + h);
+                path.push_hline_to(x);
+            }
+            path.push_close_path();
+        }
+        ElementId::Circle => {
+            let r = resolve_length(node, AId::R, 0.0);
+            let cx = resolve_length(node, AId::Cx, 0.0);
+            let cy = resolve_length(node, AId::Cy, 0.0);
+            push_ellipse(&mut path, cx, cy, r, r)?;
+        }
+        ElementId::Ellipse => {
+            let rx = resolve_length(node, AId::Rx, 0.0);
+            let ry = resolve_length(node, AId::Ry, 0.0);
+            let cx = resolve_length(node, AId::Cx, 0.0);
+            let cy = resolve_length(node, AId::Cy, 0.0);
+            push_ellipse(&mut path, cx, cy, rx, ry)?;
+        }
+        ElementId::Line => {
+            path.push_move_to(resolve_length(node, AId::X1, 0.0), resolve_length(node, AId::Y1, 0.0));
+            path.push_line_to(resolve_length(node, AId::X2, 0.0), resolve_length(node, AId::Y2, 0.0));
+        }
+          ElementId::Polyline
+        | ElementId::Polygon => {
+            let attrs = node.attributes();
+            let points = match attrs.get_value(AId::Points) {
+                Some(AttributeValue::Points(points)) if points.len() >= 2 => points,
+                _ => return None,
+            };
+
+            for (i, &(x, y)) in points.iter().enumerate() {
+                if i == 0 {
+                    path.push_move_to(x, y);
+                } else {
+                    path.push_line_to(x, y);
+                }
+            }
+
+            if node.has_tag_name(ElementId::Polygon) {
+                path.push_close_path();
+            }
+        }
+        _ => return None,
+    }
+
+    Some(path)
+}
+
+/// Replaces a basic shape with an equivalent `path` element in-place.
+///
+/// Returns `false` if the node is not a basic shape or if the shape is invalid.
+/// Invalid shapes are left untouched.
+pub fn convert_to_path(node: &mut Node) -> bool {
+    if !node.is_basic_shape() {
+        return false;
+    }
+
+    let path = match shape_to_path(node) {
+        Some(path) => path,
+        None => return false,
+    };
+
+    // Markers are allowed only on `path`, `line`, `polyline` and `polygon`,
+    // so we have to disable inherited markers for other shapes.
+    let disable_markers = match node.tag_id() {
+        Some(ElementId::Rect) | Some(ElementId::Circle) | Some(ElementId::Ellipse) => true,
+        _ => false,
+    };
+
+    if disable_markers {
+        for aid in &[AId::MarkerStart, AId::MarkerMid, AId::MarkerEnd] {
+            if let Some(AttributeValue::FuncLink(_)) = find_inherited(node, *aid) {
+                node.set_attribute((*aid, AttributeValue::None));
+            }
+        }
+    }
+
+    for aid in &[AId::X, AId::Y, AId::Width, AId::Height, AId::Rx, AId::Ry, AId::Cx, AId::Cy,
+                 AId::R, AId::X1, AId::Y1, AId::X2, AId::Y2, AId::Points] {
+        node.remove_attribute(*aid);
+    }
+
+    node.set_tag_name(ElementId::Path);
+    node.set_attribute((AId::D, path));
+
+    true
+}
+
+// Resolves rect's corner radii.
+//
+// https://www.w3.org/TR/SVG11/shapes.html#RectElement
+pub fn resolve_rx_ry(node: &Node, width: f64, height: f64) -> (f64, f64) {
+    // Negative values are an error and treated as not set.
+    let get = |aid| {
+        let n = resolve_length(node, aid, -1.0);
+        if n >= 0.0 { Some(n) } else { None }
+    };
+
+    let (rx, ry) = match (get(AId::Rx), get(AId::Ry)) {
+        (None, None) => (0.0, 0.0),
+        (Some(rx), None) => (rx, rx),
+        (None, Some(ry)) => (ry, ry),
+        (Some(rx), Some(ry)) => (rx, ry),
+    };
+
+    (rx.min(width / 2.0), ry.min(height / 2.0))
+}
+
+fn push_ellipse(path: &mut Path, cx: f64, cy: f64, rx: f64, ry: f64) -> Option<()> {
+    if !(rx > 0.0 && ry > 0.0) {
+        return None;
+    }
+
+    path.push_move_to(cx + rx, cy);
+    path.push_arc_to(rx, ry, 0.0, false, true, cx, cy + ry);
+    path.push_arc_to(rx, ry, 0.0, false, true, cx - rx, cy);
+    path.push_arc_to(rx, ry, 0.0, false, true, cx, cy - ry);
+    path.push_arc_to(rx, ry, 0.0, false, true, cx + rx, cy);
+    path.push_close_path();
+
+    Some(())
+}
@@ -10,11 +10,36 @@ rctree is a "DOM-like" tree implemented using reference counting.
 // - Node::borrow_mut marked as private
 // - Node::make_copy removed
 // - Node::make_deep_copy removed
+// - Node::try_* methods added
+// - TreeHooks added
 
 use std::fmt;
 use std::cell::{RefCell, Ref, RefMut};
 use std::rc::{Rc, Weak};
 
+use crate::{
+    BorrowError,
+    Error,
+};
+
+/// Tree modification hooks.
+///
+/// Allows the node data to keep an external state in sync with the tree structure.
+pub trait TreeHooks: Sized {
+    /// A pending state change.
+    type Update;
+
+    /// Prepares a state change caused by detaching of the node from its parent.
+    fn on_detach(node: &Node<Self>) -> Result<Self::Update, BorrowError>;
+
+    /// Prepares a state change caused by inserting of the node into the parent.
+    fn on_insert(parent: Option<&Node<Self>>, node: &Node<Self>)
+        -> Result<Self::Update, BorrowError>;
+
+    /// Applies a state change after the tree modification.
+    fn apply(update: Self::Update);
+}
+
 type Link<T> = Rc<RefCell<NodeData<T>>>;
 type WeakLink<T> = Weak<RefCell<NodeData<T>>>;
 
@@ -109,6 +134,18 @@ impl<T> Node<T> {
         Some(Node(try_opt!(try_opt!(self.0.borrow().parent.as_ref()).upgrade())))
     }
 
+    /// Returns a parent node, unless this node is the root of the tree.
+    ///
+    /// Non-panicking version of [`parent`](#method.parent).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently mutably borrowed.
+    pub fn try_parent(&self) -> Result<Option<Node<T>>, BorrowError> {
+        let data = self.0.try_borrow().map_err(|_| BorrowError)?;
+        Ok(data.parent.as_ref().and_then(|v| v.upgrade()).map(Node))
+    }
+
     /// Returns a first child of this node, unless it has no child.
     ///
     /// # Panics
@@ -118,6 +155,18 @@ impl<T> Node<T> {
         Some(Node(try_opt!(self.0.borrow().first_child.as_ref()).clone()))
     }
 
+    /// Returns a first child of this node, unless it has no child.
+    ///
+    /// Non-panicking version of [`first_child`](#method.first_child).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently mutably borrowed.
+    pub fn try_first_child(&self) -> Result<Option<Node<T>>, BorrowError> {
+        let data = self.0.try_borrow().map_err(|_| BorrowError)?;
+        Ok(data.first_child.clone().map(Node))
+    }
+
     /// Returns a last child of this node, unless it has no child.
     ///
     /// # Panics
@@ -145,6 +194,18 @@ impl<T> Node<T> {
         Some(Node(try_opt!(self.0.borrow().next_sibling.as_ref()).clone()))
     }
 
+    /// Returns the next sibling of this node, unless it is a last child.
+    ///
+    /// Non-panicking version of [`next_sibling`](#method.next_sibling).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node is currently mutably borrowed.
+    pub fn try_next_sibling(&self) -> Result<Option<Node<T>>, BorrowError> {
+        let data = self.0.try_borrow().map_err(|_| BorrowError)?;
+        Ok(data.next_sibling.clone().map(Node))
+    }
+
     /// Returns a shared reference to this node's data
     ///
     /// # Panics
@@ -163,6 +224,47 @@ impl<T> Node<T> {
         RefMut::map(self.0.borrow_mut(), |v| &mut v.data)
     }
 
+    /// Returns a shared reference to this node's data
+    /// or an error if the node is currently mutably borrowed.
+    pub(crate) fn try_borrow(&self) -> Result<Ref<'_, T>, BorrowError> {
+        let data = self.0.try_borrow().map_err(|_| BorrowError)?;
+        Ok(Ref::map(data, |v| &v.data))
+    }
+
+    /// Returns a unique/mutable reference to this node's data
+    /// or an error if the node is currently borrowed.
+    pub(crate) fn try_borrow_mut(&mut self) -> Result<RefMut<'_, T>, BorrowError> {
+        let data = self.0.try_borrow_mut().map_err(|_| BorrowError)?;
+        Ok(RefMut::map(data, |v| &mut v.data))
+    }
+
+    /// Checks that the node can be mutably borrowed.
+    pub(crate) fn check_borrow_mut(&self) -> Result<(), BorrowError> {
+        check_borrow_mut(&self.0)
+    }
+
+    /// Returns this node and its descendants, in tree order,
+    /// or an error if one of them is currently mutably borrowed.
+    pub(crate) fn try_descendants(&self) -> Result<Vec<Node<T>>, BorrowError> {
+        let mut nodes = Vec::new();
+        let mut stack = vec![self.clone()];
+        while let Some(node) = stack.pop() {
+            let idx = stack.len();
+            let mut child = node.try_first_child()?;
+            while let Some(c) = child {
+                child = c.try_next_sibling()?;
+                stack.push(c);
+            }
+
+            // Children are popped in reverse order.
+            stack[idx..].reverse();
+
+            nodes.push(node);
+        }
+
+        Ok(nodes)
+    }
+
     /// Returns an iterator of nodes to this node and its ancestors.
     ///
     /// Includes the current node.
@@ -226,8 +328,72 @@ impl<T> Node<T> {
     /// # Panics
     ///
     /// Panics if the node or one of its adjoining nodes is currently borrowed.
-    pub fn detach(&mut self) {
+    pub fn detach(&mut self) where T: TreeHooks {
+        let update = T::on_detach(self).unwrap();
+        self.0.borrow_mut().detach();
+        T::apply(update);
+    }
+
+    /// Detaches a node from its parent and siblings. Children are not affected.
+    ///
+    /// Non-panicking version of [`detach`](#method.detach).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node or one of its adjoining nodes is currently borrowed.
+    pub fn try_detach(&mut self) -> Result<(), BorrowError> where T: TreeHooks {
+        self.check_detach()?;
+        let update = T::on_detach(self)?;
         self.0.borrow_mut().detach();
+        T::apply(update);
+        Ok(())
+    }
+
+    // Checks that the node can be inserted into the current node or near it.
+    //
+    // A node cannot be inserted into itself or into its own subtree.
+    fn check_insert(&self, node: &Node<T>) -> Result<(), Error> {
+        let mut current = Some(self.clone());
+        while let Some(n) = current {
+            if n == *node {
+                return Err(Error::RecursiveInsertion);
+            }
+
+            current = n.try_parent()?;
+        }
+
+        Ok(())
+    }
+
+    // Moves the node into the parent using the `f` function.
+    //
+    // Hooks are prepared before the modification, so the tree will not be changed on error.
+    fn move_node<F>(node: &Node<T>, parent: Option<&Node<T>>, f: F) -> Result<(), BorrowError>
+        where T: TreeHooks, F: FnOnce()
+    {
+        let detach_update = T::on_detach(node)?;
+        let insert_update = T::on_insert(parent, node)?;
+        f();
+        T::apply(detach_update);
+        T::apply(insert_update);
+        Ok(())
+    }
+
+    // Checks that the node and its adjoining nodes can be mutably borrowed.
+    //
+    // All the checks must be done before any modification,
+    // so the tree will not be changed on error.
+    fn check_detach(&self) -> Result<(), BorrowError> {
+        check_borrow_mut(&self.0)?;
+
+        let data = self.0.borrow();
+        let parent = data.parent.as_ref().and_then(|v| v.upgrade());
+        let previous_sibling = data.previous_sibling.as_ref().and_then(|v| v.upgrade());
+        for link in parent.iter().chain(previous_sibling.iter()).chain(data.next_sibling.iter()) {
+            check_borrow_mut(link)?;
+        }
+
+        Ok(())
     }
 
     /// Appends a new child to this node, after existing children.
@@ -235,9 +401,16 @@ impl<T> Node<T> {
     /// # Panics
     ///
     /// Panics if the node, the new child, or one of their adjoining nodes is currently borrowed.
-    pub fn append(&mut self, new_child: Node<T>) {
+    /// Ancestors and descendants of the new child can be borrowed by the tree hooks too.
+    pub fn append(&mut self, new_child: Node<T>) where T: TreeHooks {
         assert!(*self != new_child, "a node cannot be appended to itself");
 
+        let node = new_child.clone();
+        let parent = Some(self.clone());
+        Self::move_node(&node, parent.as_ref(), || self.append_impl(new_child)).unwrap();
+    }
+
+    fn append_impl(&mut self, new_child: Node<T>) {
         let mut self_borrow = self.0.borrow_mut();
         let mut last_child_opt = None;
         {
@@ -265,14 +438,57 @@ impl<T> Node<T> {
         }
     }
 
+    /// Appends a new child to this node, after existing children.
+    ///
+    /// Non-panicking version of [`append`](#method.append).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeBorrowed`] if the node, the new child, or one of their adjoining nodes
+    ///   is currently borrowed. Or if the tree hooks cannot borrow ancestors or descendants
+    ///   of the new child.
+    /// - [`RecursiveInsertion`] if the new child is the node itself or one of its ancestors.
+    ///
+    /// [`NodeBorrowed`]: enum.Error.html
+    /// [`RecursiveInsertion`]: enum.Error.html
+    pub fn try_append(&mut self, new_child: Node<T>) -> Result<(), Error>
+        where T: TreeHooks
+    {
+        self.check_insert(&new_child)?;
+
+        new_child.check_detach()?;
+        check_borrow_mut(&self.0)?;
+        if let Some(last_child) = self.0.borrow().last_child.as_ref().and_then(|v| v.upgrade()) {
+            check_borrow_mut(&last_child)?;
+        }
+
+        let node = new_child.clone();
+        let parent = Some(self.clone());
+        Self::move_node(&node, parent.as_ref(), || {
+            // Detach before borrowing the current node,
+            // since the new node can be one of its adjoining nodes.
+            new_child.0.borrow_mut().detach();
+            self.append_impl(new_child);
+        })?;
+
+        Ok(())
+    }
+
     /// Prepends a new child to this node, before existing children.
     ///
     /// # Panics
     ///
     /// Panics if the node, the new child, or one of their adjoining nodes is currently borrowed.
-    pub fn prepend(&mut self, new_child: Node<T>) {
+    /// Ancestors and descendants of the new child can be borrowed by the tree hooks too.
+    pub fn prepend(&mut self, new_child: Node<T>) where T: TreeHooks {
         assert!(*self != new_child, "a node cannot be prepended to itself");
 
+        let node = new_child.clone();
+        let parent = Some(self.clone());
+        Self::move_node(&node, parent.as_ref(), || self.prepend_impl(new_child)).unwrap();
+    }
+
+    fn prepend_impl(&mut self, new_child: Node<T>) {
         let mut self_borrow = self.0.borrow_mut();
         {
             let mut new_child_borrow = new_child.0.borrow_mut();
@@ -297,14 +513,57 @@ impl<T> Node<T> {
         self_borrow.first_child = Some(new_child.0);
     }
 
+    /// Prepends a new child to this node, before existing children.
+    ///
+    /// Non-panicking version of [`prepend`](#method.prepend).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeBorrowed`] if the node, the new child, or one of their adjoining nodes
+    ///   is currently borrowed. Or if the tree hooks cannot borrow ancestors or descendants
+    ///   of the new child.
+    /// - [`RecursiveInsertion`] if the new child is the node itself or one of its ancestors.
+    ///
+    /// [`NodeBorrowed`]: enum.Error.html
+    /// [`RecursiveInsertion`]: enum.Error.html
+    pub fn try_prepend(&mut self, new_child: Node<T>) -> Result<(), Error>
+        where T: TreeHooks
+    {
+        self.check_insert(&new_child)?;
+
+        new_child.check_detach()?;
+        check_borrow_mut(&self.0)?;
+        if let Some(ref first_child) = self.0.borrow().first_child {
+            check_borrow_mut(first_child)?;
+        }
+
+        let node = new_child.clone();
+        let parent = Some(self.clone());
+        Self::move_node(&node, parent.as_ref(), || {
+            // Detach before borrowing the current node,
+            // since the new node can be one of its adjoining nodes.
+            new_child.0.borrow_mut().detach();
+            self.prepend_impl(new_child);
+        })?;
+
+        Ok(())
+    }
+
     /// Inserts a new sibling after this node.
     ///
     /// # Panics
     ///
     /// Panics if the node, the new sibling, or one of their adjoining nodes is currently borrowed.
-    pub fn insert_after(&mut self, new_sibling: Node<T>) {
+    /// Ancestors and descendants of the new sibling can be borrowed by the tree hooks too.
+    pub fn insert_after(&mut self, new_sibling: Node<T>) where T: TreeHooks {
         assert!(*self != new_sibling, "a node cannot be inserted after itself");
 
+        let node = new_sibling.clone();
+        let parent = self.parent();
+        Self::move_node(&node, parent.as_ref(), || self.insert_after_impl(new_sibling)).unwrap();
+    }
+
+    fn insert_after_impl(&mut self, new_sibling: Node<T>) {
         let mut self_borrow = self.0.borrow_mut();
         {
             let mut new_sibling_borrow = new_sibling.0.borrow_mut();
@@ -337,14 +596,65 @@ impl<T> Node<T> {
         self_borrow.next_sibling = Some(new_sibling.0);
     }
 
+    /// Inserts a new sibling after this node.
+    ///
+    /// Non-panicking version of [`insert_after`](#method.insert_after).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeBorrowed`] if the node, the new sibling, or one of their adjoining nodes
+    ///   is currently borrowed. Or if the tree hooks cannot borrow ancestors or descendants
+    ///   of the new sibling.
+    /// - [`RecursiveInsertion`] if the new sibling is the node itself or one of its ancestors.
+    ///
+    /// [`NodeBorrowed`]: enum.Error.html
+    /// [`RecursiveInsertion`]: enum.Error.html
+    pub fn try_insert_after(&mut self, new_sibling: Node<T>) -> Result<(), Error>
+        where T: TreeHooks
+    {
+        self.check_insert(&new_sibling)?;
+
+        new_sibling.check_detach()?;
+        check_borrow_mut(&self.0)?;
+        {
+            let data = self.0.borrow();
+            match data.next_sibling {
+                Some(ref next_sibling) => check_borrow_mut(next_sibling)?,
+                None => {
+                    if let Some(parent) = data.parent.as_ref().and_then(|v| v.upgrade()) {
+                        check_borrow_mut(&parent)?;
+                    }
+                }
+            }
+        }
+
+        let node = new_sibling.clone();
+        let parent = self.try_parent()?;
+        Self::move_node(&node, parent.as_ref(), || {
+            // Detach before borrowing the current node,
+            // since the new node can be one of its adjoining nodes.
+            new_sibling.0.borrow_mut().detach();
+            self.insert_after_impl(new_sibling);
+        })?;
+
+        Ok(())
+    }
+
     /// Inserts a new sibling before this node.
     ///
     /// # Panics
     ///
     /// Panics if the node, the new sibling, or one of their adjoining nodes is currently borrowed.
-    pub fn insert_before(&mut self, new_sibling: Node<T>) {
+    /// Ancestors and descendants of the new sibling can be borrowed by the tree hooks too.
+    pub fn insert_before(&mut self, new_sibling: Node<T>) where T: TreeHooks {
         assert!(*self != new_sibling, "a node cannot be inserted before itself");
 
+        let node = new_sibling.clone();
+        let parent = self.parent();
+        Self::move_node(&node, parent.as_ref(), || self.insert_before_impl(new_sibling)).unwrap();
+    }
+
+    fn insert_before_impl(&mut self, new_sibling: Node<T>) {
         let mut self_borrow = self.0.borrow_mut();
         let mut previous_sibling_opt = None;
         {
@@ -379,6 +689,57 @@ impl<T> Node<T> {
             }
         }
     }
+
+    /// Inserts a new sibling before this node.
+    ///
+    /// Non-panicking version of [`insert_before`](#method.insert_before).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeBorrowed`] if the node, the new sibling, or one of their adjoining nodes
+    ///   is currently borrowed. Or if the tree hooks cannot borrow ancestors or descendants
+    ///   of the new sibling.
+    /// - [`RecursiveInsertion`] if the new sibling is the node itself or one of its ancestors.
+    ///
+    /// [`NodeBorrowed`]: enum.Error.html
+    /// [`RecursiveInsertion`]: enum.Error.html
+    pub fn try_insert_before(&mut self, new_sibling: Node<T>) -> Result<(), Error>
+        where T: TreeHooks
+    {
+        self.check_insert(&new_sibling)?;
+
+        new_sibling.check_detach()?;
+        check_borrow_mut(&self.0)?;
+        {
+            let data = self.0.borrow();
+            match data.previous_sibling.as_ref().and_then(|v| v.upgrade()) {
+                Some(previous_sibling) => check_borrow_mut(&previous_sibling)?,
+                None => {
+                    if let Some(parent) = data.parent.as_ref().and_then(|v| v.upgrade()) {
+                        check_borrow_mut(&parent)?;
+                    }
+                }
+            }
+        }
+
+        let node = new_sibling.clone();
+        let parent = self.try_parent()?;
+        Self::move_node(&node, parent.as_ref(), || {
+            // Detach before borrowing the current node,
+            // since the new node can be one of its adjoining nodes.
+            new_sibling.0.borrow_mut().detach();
+            self.insert_before_impl(new_sibling);
+        })?;
+
+        Ok(())
+    }
+}
+
+fn check_borrow_mut<T>(link: &Link<T>) -> Result<(), BorrowError> {
+    match link.try_borrow_mut() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(BorrowError),
+    }
 }
 
 impl<T> NodeData<T> {
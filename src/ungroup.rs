@@ -0,0 +1,181 @@
+// Removing of redundant groups.
+
+use log::warn;
+
+use crate::{
+    Attribute,
+    AttributeId as AId,
+    AttributeQName,
+    AttributeType,
+    AttributeValue,
+    Document,
+    ElementId,
+    ElementType,
+    Node,
+    NodeType,
+};
+
+/// Removes groups without attributes and merges groups with a single child.
+pub fn ungroup_groups(doc: &mut Document) -> usize {
+    let groups: Vec<Node> = doc.root().descendants()
+        .filter(|n| n.has_tag_name(ElementId::G))
+        .collect();
+
+    let mut count = 0;
+    // Process nested groups first, so a chain of groups can be collapsed in one pass.
+    for g in groups.into_iter().rev() {
+        // Groups referenced by `use` and other elements must be preserved.
+        if g.is_used() {
+            continue;
+        }
+
+        // `switch` processes only its direct children.
+        if let Some(parent) = g.parent() {
+            if parent.has_tag_name(ElementId::Switch) {
+                continue;
+            }
+        }
+
+        if !g.attributes().is_empty() && !merge_into_child(&g) {
+            continue;
+        }
+
+        ungroup(doc, g);
+        count += 1;
+    }
+
+    count
+}
+
+fn ungroup(doc: &mut Document, mut g: Node) {
+    while let Some(mut child) = g.first_child() {
+        child.detach();
+        g.insert_before(child);
+    }
+
+    doc.remove_node(g);
+}
+
+// Moves group attributes into the only child.
+//
+// Returns `false` and leaves nodes untouched when attributes are conflicting.
+fn merge_into_child(g: &Node) -> bool {
+    let mut child = match g.first_child() {
+        Some(child) => child,
+        None => return false,
+    };
+
+    if child.next_sibling().is_some() || child.node_type() != NodeType::Element {
+        return false;
+    }
+
+    let is_transformable = child.is_graphic() || match child.tag_id() {
+        Some(ElementId::G) | Some(ElementId::A) | Some(ElementId::Switch) => true,
+        _ => false,
+    };
+
+    if !is_transformable {
+        return false;
+    }
+
+    let attrs: Vec<Attribute> = g.attributes().iter().cloned().collect();
+
+    for attr in &attrs {
+        if !is_mergeable(attr, &child) {
+            return false;
+        }
+    }
+
+    // New child attributes.
+    let mut changes = Vec::with_capacity(attrs.len());
+    for attr in attrs {
+        match attr.id() {
+            Some(AId::Transform) => {
+                if let AttributeValue::Transform(mut ts) = attr.value {
+                    if let Some(&AttributeValue::Transform(child_ts))
+                        = child.attributes().get_value(AId::Transform)
+                    {
+                        ts.append(&child_ts);
+                    }
+
+                    changes.push(Attribute::from((AId::Transform, ts)));
+                }
+            }
+            Some(AId::Opacity) => {
+                if let AttributeValue::Number(opacity) = attr.value {
+                    let child_opacity = match child.attributes().get_value(AId::Opacity) {
+                        Some(&AttributeValue::Number(n)) => n,
+                        _ => 1.0,
+                    };
+
+                    changes.push(Attribute::from((AId::Opacity, opacity * child_opacity)));
+                }
+            }
+            Some(aid) if aid.is_inheritable() => {
+                // A child value overrides an inherited one.
+                match child.attributes().get_value(aid) {
+                    Some(&AttributeValue::Inherit) | None => {}
+                    Some(_) => continue,
+                }
+
+                changes.push(attr);
+            }
+            _ => {
+                changes.push(attr);
+            }
+        }
+    }
+
+    // Original child attributes.
+    let mut prev: Vec<(AttributeQName, Option<Attribute>)> = Vec::with_capacity(changes.len());
+    for attr in changes {
+        let name = attr.name.clone();
+        let old_attr = child.attributes().get(name.as_ref()).cloned();
+        if let Err(e) = child.set_attribute_checked(attr) {
+            warn!("Failed to move the group attribute '{}' into a child: {}.", name, e);
+
+            // Restore in the reverse order, so each step returns
+            // to an already valid state and cannot fail.
+            for (name, old_attr) in prev.into_iter().rev() {
+                match old_attr {
+                    Some(old_attr) => child.set_attribute(old_attr),
+                    None => child.remove_attribute(name.as_ref()),
+                }
+            }
+
+            return false;
+        }
+
+        prev.push((name, old_attr));
+    }
+
+    true
+}
+
+fn is_mergeable(attr: &Attribute, child: &Node) -> bool {
+    match attr.value {
+        // Prevent a crosslink.
+          AttributeValue::Link(ref link)
+        | AttributeValue::FuncLink(ref link)
+        | AttributeValue::Paint(ref link, _)
+        if *link == *child || child.linked_nodes().contains(link) => return false,
+        _ => {}
+    }
+
+    match attr.id() {
+        // Those attributes are applied to the group as a whole.
+          Some(AId::ClipPath)
+        | Some(AId::Mask)
+        | Some(AId::Filter) => false,
+        Some(AId::Transform) => true,
+        Some(AId::Opacity) => {
+            match (&attr.value, child.attributes().get_value(AId::Opacity)) {
+                (&AttributeValue::Number(_), Some(&AttributeValue::Number(_))) => true,
+                (&AttributeValue::Number(_), None) => true,
+                _ => false,
+            }
+        }
+        Some(aid) if aid.is_inheritable() => true,
+        _ => !child.has_attribute(attr.name.as_ref()),
+    }
+}
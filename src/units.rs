@@ -0,0 +1,132 @@
+// Conversion of lengths into user units.
+
+use crate::{
+    AttributeId,
+    AttributeValue,
+    ElementId,
+    Length,
+    LengthUnit,
+    Node,
+};
+
+/// A default `font-size` value. Used by `em` and `ex` units.
+pub const DEFAULT_FONT_SIZE: f64 = 12.0;
+
+// Used when the viewport size cannot be resolved.
+const DEFAULT_VIEWPORT_SIZE: f64 = 100.0;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Axis {
+    X,
+    Y,
+    Diagonal,
+}
+
+// Returns an axis that should be used to resolve percentage values.
+fn axis(aid: AttributeId) -> Axis {
+    match aid {
+          AttributeId::X
+        | AttributeId::X1
+        | AttributeId::X2
+        | AttributeId::Cx
+        | AttributeId::Dx
+        | AttributeId::Fx
+        | AttributeId::Rx
+        | AttributeId::RefX
+        | AttributeId::Width
+        | AttributeId::MarkerWidth => Axis::X,
+          AttributeId::Y
+        | AttributeId::Y1
+        | AttributeId::Y2
+        | AttributeId::Cy
+        | AttributeId::Dy
+        | AttributeId::Fy
+        | AttributeId::Ry
+        | AttributeId::RefY
+        | AttributeId::Height
+        | AttributeId::MarkerHeight => Axis::Y,
+        _ => Axis::Diagonal,
+    }
+}
+
+/// Resolves a length attribute of the node in user units.
+///
+/// Returns `def` when the attribute is not set or not a length.
+/// The first value of a length list is used.
+pub fn resolve_length(node: &Node, aid: AttributeId, def: f64) -> f64 {
+    let len = match node.attributes().get_value(aid) {
+        Some(&AttributeValue::Length(len)) => len,
+        Some(AttributeValue::LengthList(list)) if !list.is_empty() => list[0],
+        Some(&AttributeValue::Number(n)) => Length::new_number(n),
+        _ => return def,
+    };
+
+    convert_length(len, node, aid)
+}
+
+/// Converts a length into user units.
+///
+/// Percentage values are resolved relative to the nearest viewport.
+pub fn convert_length(length: Length, node: &Node, aid: AttributeId) -> f64 {
+    let n = length.num;
+    match length.unit {
+        LengthUnit::None | LengthUnit::Px => n,
+        LengthUnit::Em => n * resolve_font_size(node),
+        LengthUnit::Ex => n * resolve_font_size(node) / 2.0,
+        LengthUnit::In => n * 96.0,
+        LengthUnit::Cm => n * 96.0 / 2.54,
+        LengthUnit::Mm => n * 96.0 / 25.4,
+        LengthUnit::Pt => n * 4.0 / 3.0,
+        LengthUnit::Pc => n * 16.0,
+        LengthUnit::Percent => {
+            let (w, h) = viewport_size(node);
+            let base = match axis(aid) {
+                Axis::X => w,
+                Axis::Y => h,
+                Axis::Diagonal => ((w * w + h * h) / 2.0).sqrt(),
+            };
+
+            base * n / 100.0
+        }
+    }
+}
+
+/// Resolves an inherited `font-size` of the node in user units.
+pub fn resolve_font_size(node: &Node) -> f64 {
+    for n in node.ancestors() {
+        if let Some(&AttributeValue::Length(len)) = n.attributes().get_value(AttributeId::FontSize) {
+            let parent_size = || n.parent().map(|p| resolve_font_size(&p)).unwrap_or(DEFAULT_FONT_SIZE);
+            return match len.unit {
+                LengthUnit::Em => len.num * parent_size(),
+                LengthUnit::Ex => len.num * parent_size() / 2.0,
+                LengthUnit::Percent => len.num * parent_size() / 100.0,
+                _ => convert_length(len, &n, AttributeId::FontSize),
+            };
+        }
+    }
+
+    DEFAULT_FONT_SIZE
+}
+
+// Returns the size of the nearest `svg` element.
+fn viewport_size(node: &Node) -> (f64, f64) {
+    let svg = match node.ancestors().skip(1).find(|n| n.has_tag_name(ElementId::Svg)) {
+        Some(svg) => svg,
+        None => return (DEFAULT_VIEWPORT_SIZE, DEFAULT_VIEWPORT_SIZE),
+    };
+
+    if let Some(&AttributeValue::ViewBox(vb)) = svg.attributes().get_value(AttributeId::ViewBox) {
+        return (vb.w, vb.h);
+    }
+
+    let size = |aid| {
+        match svg.attributes().get_value(aid) {
+            Some(&AttributeValue::Length(len)) if len.unit != LengthUnit::Percent => {
+                convert_length(len, &svg, aid)
+            }
+            _ => DEFAULT_VIEWPORT_SIZE,
+        }
+    };
+
+    (size(AttributeId::Width), size(AttributeId::Height))
+}
@@ -5,6 +5,7 @@ use xmlwriter::XmlWriter;
 
 use crate::{
     AttributeId,
+    AttributeType,
     AttributeValue,
     Document,
     ElementId,
@@ -99,6 +100,29 @@ pub struct WriteOptions {
     /// Default: `None`
     pub attributes_indent: Indent,
 
+    /// Use the SVG 2 `href` attribute instead of `xlink:href`.
+    ///
+    /// # Examples
+    ///
+    /// Before:
+    ///
+    /// ```text
+    /// <svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink">
+    ///     <use xlink:href="#rect1"/>
+    /// </svg>
+    /// ```
+    ///
+    /// After:
+    ///
+    /// ```text
+    /// <svg xmlns="http://www.w3.org/2000/svg">
+    ///     <use href="#rect1"/>
+    /// </svg>
+    /// ```
+    ///
+    /// Default: disabled
+    pub use_plain_href: bool,
+
     /// `svgtypes` options.
     pub values: ValueWriteOptions,
 }
@@ -109,6 +133,7 @@ impl Default for WriteOptions {
             indent: Indent::Spaces(4),
             attributes_indent: Indent::None,
             use_single_quote: false,
+            use_plain_href: false,
             values: ValueWriteOptions {
                 trim_hex_colors: false,
                 remove_leading_zero: false,
@@ -189,7 +214,8 @@ fn write_attributes(
         if node.parent().map(|v| v.is_root()) == Some(true) {
             xml.write_attribute("xmlns", "http://www.w3.org/2000/svg");
 
-            let xlink_needed = node.descendants().any(|n| n.has_attribute(AttributeId::Href));
+            let xlink_needed = !opt.use_plain_href
+                && node.descendants().any(|n| n.has_attribute(AttributeId::Href));
             if xlink_needed {
                 xml.write_attribute("xmlns:xlink", "http://www.w3.org/1999/xlink");
             }
@@ -209,9 +235,13 @@ fn write_attributes(
     ids.sort_by_key(|&(x, _)| x as usize);
 
     for &(id, name) in &ids {
+        if id.is_style_only() {
+            continue;
+        }
+
         let attr = attrs.get(name).unwrap();
         let name = match id {
-            AttributeId::Href => "xlink:href",
+            AttributeId::Href if !opt.use_plain_href => "xlink:href",
             AttributeId::Space => "xml:space",
             _ => id.as_str(),
         };
@@ -228,6 +258,22 @@ fn write_attributes(
         }
     }
 
+    // CSS properties without a presentation attribute must be written as a style.
+    if ids.iter().any(|&(id, _)| id.is_style_only()) {
+        xml.write_attribute_raw("style", |buf| {
+            let style_ids = ids.iter().filter(|&&(id, _)| id.is_style_only());
+            for (i, &(id, name)) in style_ids.enumerate() {
+                if i != 0 {
+                    buf.push(b';');
+                }
+
+                buf.extend_from_slice(id.as_str().as_bytes());
+                buf.push(b':');
+                attrs.get(name).unwrap().value.write_buf_opt(&opt.values, buf);
+            }
+        });
+    }
+
     // write non-SVG attributes
     for attr in attrs.iter() {
         if let QName::Name(ref name) = attr.name {
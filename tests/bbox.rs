@@ -0,0 +1,139 @@
+use svgdom::{
+    Document,
+    FuzzyEq,
+    Rect,
+};
+
+macro_rules! test {
+    ($name:ident, $text:expr, $id:expr, $rect:expr) => (
+        #[test]
+        fn $name() {
+            let doc = Document::from_str($text).unwrap();
+            let node = doc.root().descendants().find(|n| *n.id() == $id).unwrap();
+            let bbox = node.bbox().unwrap();
+            assert!(bbox.fuzzy_eq(&$rect), "{:?} != {:?}", bbox, $rect);
+        }
+    )
+}
+
+test!(rect_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='r' x='10' y='20' width='30' height='40'/>
+</svg>", "r", Rect::new(10.0, 20.0, 30.0, 40.0));
+
+test!(rect_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g transform='translate(100 0)'>
+        <rect id='r' width='30' height='40' transform='rotate(90)'/>
+    </g>
+</svg>", "r", Rect::new(60.0, 0.0, 40.0, 30.0));
+
+test!(rect_3,
+"<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 100'>
+    <rect id='r' width='50%' height='1in'/>
+</svg>", "r", Rect::new(0.0, 0.0, 100.0, 96.0));
+
+test!(circle_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <circle id='c' cx='50' cy='50' r='10' transform='rotate(45 50 50)'/>
+</svg>", "c", Rect::new(40.0, 40.0, 20.0, 20.0));
+
+test!(ellipse_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <ellipse id='e' rx='20' ry='10' transform='rotate(90)'/>
+</svg>", "e", Rect::new(-10.0, -20.0, 20.0, 40.0));
+
+test!(line_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <line id='l' x1='10' y1='10' x2='50' y2='10'/>
+</svg>", "l", Rect::new(10.0, 10.0, 40.0, 0.0));
+
+test!(polygon_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <polygon id='p' points='10 20 50 0 30 40'/>
+</svg>", "p", Rect::new(10.0, 0.0, 40.0, 40.0));
+
+test!(path_cubic_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path id='p' d='M 0 0 C 0 100 100 100 100 0'/>
+</svg>", "p", Rect::new(0.0, 0.0, 100.0, 75.0));
+
+test!(path_quad_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path id='p' d='M 0 0 Q 50 100 100 0 T 200 0'/>
+</svg>", "p", Rect::new(0.0, -50.0, 200.0, 100.0));
+
+test!(path_arc_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path id='p' d='M 0 50 A 50 50 0 0 1 100 50'/>
+</svg>", "p", Rect::new(0.0, 0.0, 100.0, 50.0));
+
+test!(path_arc_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path id='p' d='M 0 50 a 50 50 0 0 0 100 0'/>
+</svg>", "p", Rect::new(0.0, 50.0, 100.0, 50.0));
+
+test!(path_relative_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path id='p' d='m 10 10 h 20 v 30 z m 5 5 l -20 0'/>
+</svg>", "p", Rect::new(-5.0, 10.0, 35.0, 30.0));
+
+test!(group_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g id='g' transform='scale(2)'>
+        <rect width='10' height='10'/>
+        <rect x='20' y='20' width='10' height='10' display='none'/>
+        <circle cx='20' cy='5' r='5'/>
+        <linearGradient/>
+    </g>
+</svg>", "g", Rect::new(0.0, 0.0, 50.0, 20.0));
+
+test!(use_1,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <rect id='r' x='5' width='10' height='10'/>
+    </defs>
+    <use id='u' x='20' y='30' xlink:href='#r'/>
+</svg>", "u", Rect::new(25.0, 30.0, 10.0, 10.0));
+
+test!(use_2,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <symbol id='s'>
+        <rect width='10' height='10'/>
+    </symbol>
+    <use id='u' transform='translate(5)' xlink:href='#s'/>
+</svg>", "u", Rect::new(5.0, 0.0, 10.0, 10.0));
+
+test!(text_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <text id='t' x='100' y='50' font-size='10' text-anchor='middle'>Text</text>
+</svg>", "t", Rect::new(90.0, 42.0, 20.0, 10.0));
+
+#[test]
+fn stroke_bbox_1() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g stroke='black' stroke-width='4' transform='scale(2 3)'>
+        <rect id='r' width='10' height='10'/>
+    </g>
+</svg>").unwrap();
+
+    let node = doc.root().descendants().find(|n| *n.id() == "r").unwrap();
+    assert!(node.stroke_bbox().unwrap().fuzzy_eq(&Rect::new(-4.0, -6.0, 28.0, 42.0)));
+    assert!(node.bbox().unwrap().fuzzy_eq(&Rect::new(0.0, 0.0, 20.0, 30.0)));
+}
+
+#[test]
+fn no_bbox_1() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg'/>
+    <rect id='r' width='0' height='10'/>
+    <g id='g'/>
+</svg>").unwrap();
+
+    for id in &["lg", "r", "g"] {
+        let node = doc.root().descendants().find(|n| *n.id() == *id).unwrap();
+        assert_eq!(node.bbox(), None);
+    }
+}
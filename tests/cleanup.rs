@@ -0,0 +1,332 @@
+#[macro_use] extern crate pretty_assertions;
+
+use svgdom::{
+    Document,
+    WriteOptions,
+};
+
+macro_rules! test {
+    ($name:ident, $method:ident, $in_text:expr, $out_text:expr) => (
+        #[test]
+        fn $name() {
+            let mut doc = Document::from_str($in_text).unwrap();
+            doc.$method();
+
+            let mut opt = WriteOptions::default();
+            opt.use_single_quote = true;
+
+            assert_eq!(doc.to_string_with_opt(&opt), $out_text);
+        }
+    )
+}
+
+test!(default_1, remove_default_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='black' fill-opacity='1' stroke='none' stroke-width='1' stroke-miterlimit='4'
+          display='inline' opacity='0.5' fill-rule='nonzero' visibility='visible'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect opacity='0.5'/>
+</svg>
+");
+
+test!(default_2, remove_default_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g stroke='red' display='none'>
+        <rect stroke='none' display='inline'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g display='none' stroke='#ff0000'>
+        <rect stroke='none'/>
+    </g>
+</svg>
+");
+
+test!(default_3, remove_default_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill='black'>
+        <rect fill='black'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g>
+        <rect/>
+    </g>
+</svg>
+");
+
+test!(default_4, remove_default_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g visibility='hidden'>
+        <rect visibility='visible'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g visibility='hidden'>
+        <rect visibility='visible'/>
+    </g>
+</svg>
+");
+
+test!(default_use_1, remove_default_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <g id='g1'>
+            <rect fill='black' opacity='1'/>
+        </g>
+    </defs>
+    <use xlink:href='#g1' fill='red'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <g id='g1'>
+            <rect fill='#000000'/>
+        </g>
+    </defs>
+    <use fill='#ff0000' xlink:href='#g1'/>
+</svg>
+");
+
+test!(inherited_1, remove_inherited_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill='red' stroke-width='2' opacity='0.5'>
+        <g fill='inherit'>
+            <rect fill='red' stroke-width='3' opacity='0.5'/>
+        </g>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill='#ff0000' opacity='0.5' stroke-width='2'>
+        <g fill='inherit'>
+            <rect opacity='0.5' stroke-width='3'/>
+        </g>
+    </g>
+</svg>
+");
+
+test!(inherited_2, remove_inherited_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='black' stroke='none'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect/>
+</svg>
+");
+
+test!(inherited_3, remove_inherited_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g visibility='hidden'>
+        <rect visibility='hidden'/>
+        <rect visibility='visible'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g visibility='hidden'>
+        <rect/>
+        <rect visibility='visible'/>
+    </g>
+</svg>
+");
+
+test!(inherited_use_1, remove_inherited_attributes,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <g fill='red'>
+        <g id='g1' fill='red'>
+            <rect fill='red'/>
+        </g>
+    </g>
+    <use xlink:href='#g1' fill='green'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <g fill='#ff0000'>
+        <g id='g1' fill='#ff0000'>
+            <rect/>
+        </g>
+    </g>
+    <use fill='#008000' xlink:href='#g1'/>
+</svg>
+");
+
+test!(unused_defs_1, remove_unused_defs,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <linearGradient id='lg1'/>
+        <pattern id='patt1'>
+            <rect fill='url(#lg1)'/>
+        </pattern>
+        <rect id='rect1' fill='url(#patt1)'/>
+        <g>
+            <rect id='rect2'/>
+        </g>
+        <title/>
+    </defs>
+    <use xlink:href='#rect2'/>
+    <clipPath id='clip1'/>
+    <marker id='marker1'/>
+    <path marker-end='url(#marker1)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <g>
+            <rect id='rect2'/>
+        </g>
+        <title/>
+    </defs>
+    <use xlink:href='#rect2'/>
+    <marker id='marker1'/>
+    <path marker-end='url(#marker1)'/>
+</svg>
+");
+
+test!(unused_defs_2, remove_unused_defs,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <pattern id='patt1'>
+        <rect fill='url(#patt1)'/>
+    </pattern>
+    <mask id='mask1'>
+        <rect/>
+    </mask>
+    <rect mask='url(#mask1)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <mask id='mask1'>
+        <rect/>
+    </mask>
+    <rect mask='url(#mask1)'/>
+</svg>
+");
+
+test!(unused_ids_1, remove_unused_ids,
+"<svg id='svg1' xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <linearGradient id='lg2' xlink:href='#lg1'/>
+    <rect id='rect1' fill='url(#lg2)'/>
+    <rect id='rect2'/>
+    <use xlink:href='#rect2'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <linearGradient id='lg2' xlink:href='#lg1'/>
+    <rect fill='url(#lg2)'/>
+    <rect id='rect2'/>
+    <use xlink:href='#rect2'/>
+</svg>
+");
+
+test!(duplicated_defs_1, remove_duplicated_defs,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1' x1='1' y1='2'>
+        <stop offset='0' stop-color='red'/>
+    </linearGradient>
+    <linearGradient id='lg2' y1='2' x1='1'>
+        <stop offset='0' stop-color='red'/>
+    </linearGradient>
+    <linearGradient id='lg3' x1='1' y1='2'>
+        <stop offset='1' stop-color='red'/>
+    </linearGradient>
+    <rect fill='url(#lg1)'/>
+    <rect fill='url(#lg2) red' stroke='url(#lg2)'/>
+    <rect fill='url(#lg3)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1' x1='1' y1='2'>
+        <stop offset='0' stop-color='#ff0000'/>
+    </linearGradient>
+    <linearGradient id='lg3' x1='1' y1='2'>
+        <stop offset='1' stop-color='#ff0000'/>
+    </linearGradient>
+    <rect fill='url(#lg1)'/>
+    <rect fill='url(#lg1) #ff0000' stroke='url(#lg1)'/>
+    <rect fill='url(#lg3)'/>
+</svg>
+");
+
+// Elements that were linking to different duplicates are equal after the first pass.
+test!(duplicated_defs_2, remove_duplicated_defs,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <linearGradient id='lg2'/>
+    <linearGradient id='lg3' xlink:href='#lg1'/>
+    <linearGradient id='lg4' xlink:href='#lg2'/>
+    <clipPath id='clip1'>
+        <rect width='10'/>
+    </clipPath>
+    <clipPath id='clip2'>
+        <rect width='10'/>
+    </clipPath>
+    <rect fill='url(#lg3)' clip-path='url(#clip1)'/>
+    <rect fill='url(#lg4)' clip-path='url(#clip2)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <linearGradient id='lg3' xlink:href='#lg1'/>
+    <clipPath id='clip1'>
+        <rect width='10'/>
+    </clipPath>
+    <rect clip-path='url(#clip1)' fill='url(#lg3)'/>
+    <rect clip-path='url(#clip1)' fill='url(#lg3)'/>
+</svg>
+");
+
+// Elements with used children are preserved.
+test!(duplicated_defs_3, remove_duplicated_defs,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <clipPath id='clip1'>
+        <rect width='10'/>
+    </clipPath>
+    <clipPath id='clip2'>
+        <rect id='rect1' width='10'/>
+    </clipPath>
+    <use xlink:href='#rect1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <clipPath id='clip1'>
+        <rect width='10'/>
+    </clipPath>
+    <clipPath id='clip2'>
+        <rect id='rect1' width='10'/>
+    </clipPath>
+    <use xlink:href='#rect1'/>
+</svg>
+");
+
+// Elements under ancestors with different inherited attributes are preserved.
+test!(duplicated_defs_4, remove_duplicated_defs,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g clip-rule='evenodd'>
+        <clipPath id='clip1'>
+            <path d='M 0 0 L 10 0 L 10 10 Z'/>
+        </clipPath>
+    </g>
+    <g clip-rule='nonzero'>
+        <clipPath id='clip2'>
+            <path d='M 0 0 L 10 0 L 10 10 Z'/>
+        </clipPath>
+    </g>
+    <g clip-rule='evenodd'>
+        <clipPath id='clip3'>
+            <path d='M 0 0 L 10 0 L 10 10 Z'/>
+        </clipPath>
+    </g>
+    <rect clip-path='url(#clip1)'/>
+    <rect clip-path='url(#clip2)'/>
+    <rect clip-path='url(#clip3)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g clip-rule='evenodd'>
+        <clipPath id='clip1'>
+            <path d='M 0 0 L 10 0 L 10 10 Z'/>
+        </clipPath>
+    </g>
+    <g clip-rule='nonzero'>
+        <clipPath id='clip2'>
+            <path d='M 0 0 L 10 0 L 10 10 Z'/>
+        </clipPath>
+    </g>
+    <g clip-rule='evenodd'/>
+    <rect clip-path='url(#clip1)'/>
+    <rect clip-path='url(#clip2)'/>
+    <rect clip-path='url(#clip1)'/>
+</svg>
+");
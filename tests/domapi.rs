@@ -3,8 +3,10 @@
 use svgdom::{
     AttributeId as AId,
     AttributeValue,
+    BorrowError,
     Document,
     ElementId as EId,
+    Error,
     WriteOptions,
 };
 
@@ -414,3 +416,209 @@ fn set_attr_1() {
     rect.set_attribute((AId::Href, rect2));
     assert_eq!(rect.attributes().get(AId::Href).unwrap().to_string(), "xlink:href='#rect2'");
 }
+
+#[test]
+fn try_attributes_1() {
+    let mut doc = Document::new();
+    let mut rect = doc.create_element(EId::Rect);
+    rect.set_attribute((AId::X, 1.0));
+
+    {
+        let node = rect.clone();
+        let _attrs = node.attributes();
+        assert_eq!(rect.try_attributes().is_ok(), true);
+        assert_eq!(rect.clone().try_attributes_mut().err(), Some(BorrowError));
+        assert_eq!(rect.try_set_attribute((AId::Y, 1.0)).is_err(), true);
+        assert_eq!(rect.try_remove_attribute(AId::X), Err(BorrowError));
+        assert_eq!(rect.try_set_id("rect1"), Err(BorrowError));
+    }
+
+    {
+        let mut node = rect.clone();
+        let _attrs = node.attributes_mut();
+        assert_eq!(rect.try_attributes().err(), Some(BorrowError));
+        assert_eq!(rect.try_id().err(), Some(BorrowError));
+        assert_eq!(rect.try_tag_name().err(), Some(BorrowError));
+    }
+
+    assert_eq!(rect.try_set_attribute((AId::Y, 1.0)).is_ok(), true);
+    assert_eq!(rect.try_remove_attribute(AId::X), Ok(()));
+    assert_eq!(rect.has_attribute(AId::X), false);
+    assert_eq!(rect.has_attribute(AId::Y), true);
+}
+
+#[test]
+fn try_set_attribute_1() {
+    let mut doc = Document::new();
+    let mut svg = doc.create_element(EId::Svg);
+    let mut lg1 = doc.create_element(EId::LinearGradient);
+    let mut lg2 = doc.create_element(EId::LinearGradient);
+    let mut rect = doc.create_element(EId::Rect);
+    lg1.set_id("lg1");
+    lg2.set_id("lg2");
+    doc.root().append(svg.clone());
+    svg.append(lg1.clone());
+    svg.append(lg2.clone());
+    svg.append(rect.clone());
+
+    rect.set_attribute((AId::Fill, lg1.clone()));
+
+    // A new linked node is borrowed.
+    {
+        let node = lg2.clone();
+        let _attrs = node.attributes();
+        match rect.try_set_attribute((AId::Fill, lg2.clone())) {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    // An old linked node is borrowed.
+    {
+        let node = lg1.clone();
+        let _attrs = node.attributes();
+        match rect.try_set_attribute((AId::Fill, lg2.clone())) {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    // An ancestor visited by the links cycle detection is borrowed.
+    {
+        let mut node = svg.clone();
+        let _attrs = node.attributes_mut();
+        match rect.try_set_attribute((AId::Fill, lg2.clone())) {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    // Nothing was changed.
+    assert_eq!(lg1.uses_count(), 1);
+    assert_eq!(lg2.uses_count(), 0);
+
+    assert_eq!(rect.try_set_attribute((AId::Fill, lg2.clone())).is_ok(), true);
+    assert_eq!(lg1.uses_count(), 0);
+    assert_eq!(lg2.uses_count(), 1);
+}
+
+#[test]
+fn try_append_1() {
+    let mut doc = Document::new();
+    let mut svg = doc.create_element(EId::Svg);
+    let mut g = doc.create_element(EId::G);
+    let rect1 = doc.create_element(EId::Rect);
+    let rect2 = doc.create_element(EId::Rect);
+    doc.root().append(svg.clone());
+    svg.append(g.clone());
+    g.append(rect1.clone());
+
+    // The new child is borrowed.
+    {
+        let mut node = rect2.clone();
+        let _attrs = node.attributes_mut();
+        match svg.try_append(rect2.clone()) {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    // The last child is borrowed.
+    {
+        let mut node = g.clone();
+        let _attrs = node.attributes_mut();
+        match svg.try_append(rect2.clone()) {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+        match svg.try_prepend(rect2.clone()) {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    // The current parent of the new child is borrowed.
+    {
+        let mut node = g.clone();
+        let _attrs = node.attributes_mut();
+        match svg.try_append(rect1.clone()) {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+        assert_eq!(rect1.clone().try_detach(), Err(BorrowError));
+    }
+
+    // A sibling is borrowed.
+    {
+        let mut node = rect1.clone();
+        let _attrs = node.attributes_mut();
+        assert_eq!(g.try_insert_after(rect2.clone()).is_ok(), true);
+        let res = rect2.clone().try_insert_before(rect1.clone());
+        match res {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+        let res = rect1.clone().try_insert_after(rect2.clone());
+        match res {
+            Err(Error::NodeBorrowed) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    assert_eq!(svg.children().count(), 2);
+    assert_eq!(svg.try_append(rect1.clone()).is_ok(), true);
+    assert_eq!(g.has_children(), false);
+    assert_eq!(svg.last_child(), Some(rect1.clone()));
+
+    // The node is already the last child.
+    assert_eq!(svg.try_append(rect1.clone()).is_ok(), true);
+    assert_eq!(svg.children().count(), 3);
+}
+
+#[test]
+fn try_append_2() {
+    let mut doc = Document::new();
+    let mut svg = doc.create_element(EId::Svg);
+    let mut g1 = doc.create_element(EId::G);
+    let mut g2 = doc.create_element(EId::G);
+    doc.root().append(svg.clone());
+    svg.append(g1.clone());
+    g1.append(g2.clone());
+
+    // A node cannot be inserted into itself.
+    match g1.try_append(g1.clone()) {
+        Err(Error::RecursiveInsertion) => {}
+        _ => unreachable!(),
+    }
+    match g1.try_prepend(g1.clone()) {
+        Err(Error::RecursiveInsertion) => {}
+        _ => unreachable!(),
+    }
+    match g1.try_insert_after(g1.clone()) {
+        Err(Error::RecursiveInsertion) => {}
+        _ => unreachable!(),
+    }
+    match g1.try_insert_before(g1.clone()) {
+        Err(Error::RecursiveInsertion) => {}
+        _ => unreachable!(),
+    }
+
+    // Or into its own subtree.
+    match g2.try_append(svg.clone()) {
+        Err(Error::RecursiveInsertion) => {}
+        _ => unreachable!(),
+    }
+    match g2.try_insert_after(g1.clone()) {
+        Err(Error::RecursiveInsertion) => {}
+        _ => unreachable!(),
+    }
+
+    // Nothing was changed.
+    assert_eq!(g2.parent(), Some(g1.clone()));
+    assert_eq!(g1.parent(), Some(svg.clone()));
+    assert_eq!(svg.children().count(), 1);
+
+    // Moving a node up is allowed.
+    assert_eq!(g1.try_insert_after(g2.clone()).is_ok(), true);
+    assert_eq!(svg.children().count(), 2);
+}
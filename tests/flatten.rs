@@ -0,0 +1,169 @@
+#[macro_use] extern crate pretty_assertions;
+
+use svgdom::{
+    Document,
+    WriteOptions,
+};
+
+macro_rules! test {
+    ($name:ident, $in_text:expr, $out_text:expr) => (
+        #[test]
+        fn $name() {
+            let mut doc = Document::from_str($in_text).unwrap();
+            doc.flatten_transforms();
+
+            let mut opt = WriteOptions::default();
+            opt.use_single_quote = true;
+
+            assert_eq!(doc.to_string_with_opt(&opt), $out_text);
+        }
+    )
+}
+
+test!(group_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g transform='translate(10 20)'>
+        <g transform='scale(2)'>
+            <rect width='10' height='20' rx='2'/>
+        </g>
+        <linearGradient id='lg'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g>
+        <g>
+            <rect height='40' rx='4' ry='4' width='20' x='10' y='20'/>
+        </g>
+        <linearGradient id='lg'/>
+    </g>
+</svg>
+");
+
+test!(group_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <clipPath id='clip'/>
+    <g transform='translate(10 20)' clip-path='url(#clip)'>
+        <rect width='10' height='20'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <clipPath id='clip'/>
+    <g clip-path='url(#clip)' transform='matrix(1 0 0 1 10 20)'>
+        <rect height='20' width='10'/>
+    </g>
+</svg>
+");
+
+test!(path_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='m 10 20 h 10 v 10 z' transform='matrix(0 1 -1 0 0 0)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M -20 10 L -20 20 L -30 20 Z'/>
+</svg>
+");
+
+test!(path_arc_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 0 0 A 20 10 0 0 1 40 0' transform='scale(1 -2)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 0 0 A 20 20 0 0 0 40 0'/>
+</svg>
+");
+
+test!(path_arc_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 0 0 A 20 10 0 0 1 40 0' transform='matrix(0 1 -1 0 0 0)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 0 0 A 20 10 90 0 1 0 40'/>
+</svg>
+");
+
+test!(shapes_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect x='10' width='10' height='20' transform='scale(-1 2)'/>
+    <rect width='10' height='20' transform='rotate(45)'/>
+    <circle cx='10' r='5' transform='rotate(90)'/>
+    <ellipse cx='10' rx='5' ry='10' transform='matrix(2 0 0 3 1 1)'/>
+    <line x2='10' transform='skewY(45)'/>
+    <polygon points='0 0 10 0 10 10' transform='translate(5 5)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect height='40' width='10' x='-20' y='0'/>
+    <rect height='20' transform='matrix(0.70710678119 0.70710678119 -0.70710678119 0.70710678119 0 0)' \
+width='10'/>
+    <circle cx='0' cy='10' r='5'/>
+    <ellipse cx='21' cy='1' rx='10' ry='30'/>
+    <line x1='0' x2='10' y1='0' y2='10'/>
+    <polygon points='5 5 15 5 15 15'/>
+</svg>
+");
+
+test!(stroke_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g stroke='black' stroke-width='2' stroke-dasharray='1 2'>
+        <path d='M 0 0 L 10 0' transform='scale(2)'/>
+        <path d='M 0 0 L 10 0' transform='scale(2 3)'/>
+        <path d='M 0 0 L 10 0' transform='scale(2)' stroke='none'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g stroke='#000000' stroke-dasharray='1 2' stroke-width='2'>
+        <path d='M 0 0 L 20 0' stroke-dasharray='2 4' stroke-width='4'/>
+        <path d='M 0 0 L 10 0' transform='matrix(2 0 0 3 0 0)'/>
+        <path d='M 0 0 L 20 0' stroke='none'/>
+    </g>
+</svg>
+");
+
+test!(gradient_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1' gradientUnits='userSpaceOnUse' gradientTransform='scale(2)'/>
+    <path d='M 0 0 L 10 0 L 10 10 Z' fill='url(#lg1)' transform='translate(10 20)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1' gradientTransform='matrix(2 0 0 2 10 20)' gradientUnits='userSpaceOnUse'/>
+    <path d='M 10 20 L 20 20 L 20 30 Z' fill='url(#lg1)'/>
+</svg>
+");
+
+test!(gradient_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <path d='M 0 0 L 10 0 L 10 10 Z' fill='url(#lg1)' transform='matrix(0 1 -1 0 0 0)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1' gradientTransform='matrix(0 1 -1 0 1 0)'/>
+    <path d='M 0 0 L 0 10 L -10 10 Z' fill='url(#lg1)'/>
+</svg>
+");
+
+test!(gradient_3,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <path d='M 0 0 L 10 0' fill='url(#lg1)' transform='translate(10)'/>
+    <path d='M 0 0 L 10 0' fill='url(#lg1)' transform='translate(10)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <path d='M 0 0 L 10 0' fill='url(#lg1)' transform='matrix(1 0 0 1 10 0)'/>
+    <path d='M 0 0 L 10 0' fill='url(#lg1)' transform='matrix(1 0 0 1 10 0)'/>
+</svg>
+");
+
+test!(preserved_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <marker id='m'/>
+    <text transform='translate(10)'>Text</text>
+    <path d='M 0 0 L 10 0' marker-end='url(#m)' transform='translate(10)'/>
+    <path d='M 0 0 L 10 0' transform='matrix(1 0 0 1 0 0)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <marker id='m'/>
+    <text transform='matrix(1 0 0 1 10 0)'>Text</text>
+    <path d='M 0 0 L 10 0' marker-end='url(#m)' transform='matrix(1 0 0 1 10 0)'/>
+    <path d='M 0 0 L 10 0'/>
+</svg>
+");
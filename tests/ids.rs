@@ -0,0 +1,195 @@
+use svgdom::{
+    Document,
+    ElementId,
+    Error,
+    WriteOptions,
+};
+
+fn to_string(doc: &Document) -> String {
+    let opt = WriteOptions {
+        use_single_quote: true,
+        ..WriteOptions::default()
+    };
+    doc.to_string_with_opt(&opt)
+}
+
+#[test]
+fn rename_ids_1() {
+    let mut doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <linearGradient id='lg2' xlink:href='#lg1'/>
+    <rect id='rect1' fill='url(#lg2)'/>
+</svg>").unwrap();
+
+    doc.rename_ids(|id| if id == "rect1" { String::new() } else { id.to_uppercase() }).unwrap();
+
+    assert_eq!(to_string(&doc),
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='LG1'/>
+    <linearGradient id='LG2' xlink:href='#LG1'/>
+    <rect fill='url(#LG2)'/>
+</svg>
+");
+}
+
+#[test]
+fn rename_ids_2() {
+    let text =
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <rect id='rect1' fill='url(#lg1)'/>
+</svg>
+";
+
+    let mut doc = Document::from_str(text).unwrap();
+
+    match doc.rename_ids(|_| "id".to_string()) {
+        Err(Error::DuplicatedId(ref id)) if id == "id" => {}
+        _ => panic!("should be an error"),
+    }
+
+    match doc.rename_ids(|id| if id == "lg1" { String::new() } else { id.to_string() }) {
+        Err(Error::ElementMustHaveAnId) => {}
+        _ => panic!("should be an error"),
+    }
+
+    // Nothing should be renamed on error.
+    assert_eq!(to_string(&doc), text);
+}
+
+#[test]
+fn minify_ids_1() {
+    let mut text = String::from("<svg xmlns='http://www.w3.org/2000/svg'>\n");
+    for i in 0..120 {
+        text.push_str(&format!("    <rect id='rect{}'/>\n", i));
+    }
+    text.push_str("    <rect fill='url(#rect119)'/>\n");
+    text.push_str("</svg>\n");
+
+    let mut doc = Document::from_str(&text).unwrap();
+    doc.minify_ids();
+
+    let ids: Vec<String> = doc.root().descendants().map(|n| n.id().clone()).collect();
+    // Skip the root, the `svg` element and the most used `rect119`.
+    assert_eq!(ids[2], "b");
+    assert_eq!(ids[26], "z");
+    assert_eq!(ids[27], "A");
+    assert_eq!(ids[52], "Z");
+    assert_eq!(ids[53], "aa");
+    assert_eq!(ids[114], "a9");
+    assert_eq!(ids[115], "ba");
+    assert_eq!(ids[121], "a");
+    assert_eq!(ids[122], "");
+}
+
+#[test]
+fn make_ids_unique_1() {
+    let mut doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <linearGradient id='lg1'/>
+    <rect fill='url(#lg1)'/>
+    <rect id='lg1'/>
+</svg>").unwrap();
+
+    assert_eq!(doc.make_ids_unique(), 2);
+
+    assert_eq!(to_string(&doc),
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <linearGradient id='lg1-1'/>
+    <rect fill='url(#lg1)'/>
+    <rect id='lg1-2'/>
+</svg>
+");
+
+    assert_eq!(doc.make_ids_unique(), 0);
+}
+
+#[test]
+fn node_by_id_1() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='rect1'/>
+    <circle id='rect1'/>
+</svg>").unwrap();
+
+    // The first element wins.
+    assert!(doc.node_by_id("rect1").unwrap().has_tag_name(ElementId::Rect));
+    assert_eq!(doc.node_by_id(""), None);
+    assert_eq!(doc.node_by_id("rect2"), None);
+}
+
+#[test]
+fn node_by_id_2() {
+    let mut doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='rect1'/>
+    <rect id='rect2'/>
+</svg>").unwrap();
+
+    let mut rect1 = doc.node_by_id("rect1").unwrap();
+    let mut rect2 = doc.node_by_id("rect2").unwrap();
+
+    rect1.set_id("rect3");
+    assert_eq!(doc.node_by_id("rect1"), None);
+    assert_eq!(doc.node_by_id("rect3"), Some(rect1.clone()));
+
+    rect2.detach();
+    assert_eq!(doc.node_by_id("rect2"), None);
+
+    doc.svg_element().unwrap().append(rect2.clone());
+    assert_eq!(doc.node_by_id("rect2"), Some(rect2.clone()));
+
+    doc.remove_node(rect2);
+    assert_eq!(doc.node_by_id("rect2"), None);
+
+    let mut circle = doc.create_element(ElementId::Circle);
+    circle.set_id("circle1");
+    assert_eq!(doc.node_by_id("circle1"), None);
+
+    doc.svg_element().unwrap().append(circle.clone());
+    assert_eq!(doc.node_by_id("circle1"), Some(circle));
+}
+
+#[test]
+fn node_by_id_3() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='rect1'/>
+    <circle id='rect1'/>
+</svg>").unwrap();
+
+    let mut rect = doc.node_by_id("rect1").unwrap();
+    rect.set_id("");
+
+    // The next element with the same id is used.
+    assert!(doc.node_by_id("rect1").unwrap().has_tag_name(ElementId::Circle));
+}
+
+// The index is updated for the whole subtree.
+#[test]
+fn node_by_id_4() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g id='g1'>
+        <rect id='rect1'/>
+    </g>
+    <rect id='rect1'/>
+</svg>").unwrap();
+
+    let mut g = doc.node_by_id("g1").unwrap();
+    let mut rect = g.first_child().unwrap();
+
+    g.detach();
+    assert_eq!(doc.node_by_id("g1"), None);
+    assert!(doc.node_by_id("rect1").unwrap().parent().unwrap().has_tag_name(ElementId::Svg));
+
+    rect.set_id("rect2");
+    assert_eq!(doc.node_by_id("rect2"), None);
+
+    doc.svg_element().unwrap().prepend(g.clone());
+    assert_eq!(doc.node_by_id("g1"), Some(g));
+    assert_eq!(doc.node_by_id("rect2"), Some(rect));
+}
@@ -0,0 +1,222 @@
+#[macro_use] extern crate pretty_assertions;
+
+use svgdom::{
+    AttributeId as AId,
+    AttributeValue,
+    Document,
+    ElementId as EId,
+    WriteOptions,
+};
+
+fn write_options() -> WriteOptions {
+    WriteOptions {
+        use_single_quote: true,
+        ..WriteOptions::default()
+    }
+}
+
+#[test]
+fn import_node_1() {
+    let other = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'>
+        <stop offset='0'/>
+    </linearGradient>
+    <linearGradient id='lg2' xlink:href='#lg1'/>
+    <clipPath id='clip1'>
+        <rect width='10' height='10'/>
+    </clipPath>
+    <g id='icon' clip-path='url(#clip1)'>
+        <rect id='rect1' fill='url(#lg2)'/>
+        <use xlink:href='#rect1'/>
+    </g>
+</svg>").unwrap();
+
+    let mut doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <defs>
+        <linearGradient id='lg1'/>
+    </defs>
+    <rect id='rect1'/>
+</svg>").unwrap();
+
+    let icon = other.node_by_id("icon").unwrap();
+    let node = doc.import_node(&other, icon, true);
+    doc.svg_element().unwrap().append(node);
+
+    assert_eq!(doc.to_string_with_opt(&write_options()),
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <linearGradient id='lg1'/>
+        <clipPath id='clip1'>
+            <rect height='10' width='10'/>
+        </clipPath>
+        <linearGradient id='lg2' xlink:href='#lg1-1'/>
+        <linearGradient id='lg1-1'>
+            <stop offset='0'/>
+        </linearGradient>
+    </defs>
+    <rect id='rect1'/>
+    <g id='icon' clip-path='url(#clip1)'>
+        <rect id='rect1-1' fill='url(#lg2)'/>
+        <use xlink:href='#rect1-1'/>
+    </g>
+</svg>
+");
+
+    // The source document is not affected.
+    assert_eq!(other.node_by_id("lg1").unwrap().uses_count(), 1);
+}
+
+#[test]
+fn import_node_2() {
+    let other = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g id='g1' opacity='0.5'>
+        <rect/>
+    </g>
+</svg>").unwrap();
+
+    let mut doc = Document::new();
+    let g = other.node_by_id("g1").unwrap();
+
+    let node = doc.import_node(&other, g.clone(), false);
+    assert_eq!(node.has_tag_name(EId::G), true);
+    assert_eq!(node.has_children(), false);
+    assert_eq!(*node.id(), "g1");
+
+    let node = doc.import_node(&other, g, true);
+    assert_eq!(node.children().count(), 1);
+    // Nodes are detached, so ids are renamed only on collisions during the import.
+    assert_eq!(*node.id(), "g1");
+}
+
+#[test]
+#[should_panic]
+fn import_node_3() {
+    let other = Document::from_str("<svg xmlns='http://www.w3.org/2000/svg'/>").unwrap();
+    let mut doc = Document::new();
+    let svg = doc.create_element(EId::Svg);
+    doc.import_node(&other, svg, true);
+}
+
+#[test]
+fn copy_node_deep_with_ids_1() {
+    let mut doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <g id='g1'>
+        <rect id='rect1' fill='url(#lg1)'/>
+        <use xlink:href='#rect1'/>
+        <use xlink:href='#g2'/>
+    </g>
+    <g id='g2'/>
+</svg>").unwrap();
+
+    let g = doc.node_by_id("g1").unwrap();
+    let new_g = doc.copy_node_deep_with_ids(g);
+    doc.svg_element().unwrap().append(new_g);
+
+    assert_eq!(doc.node_by_id("lg1").unwrap().uses_count(), 2);
+    assert_eq!(doc.node_by_id("rect1").unwrap().uses_count(), 1);
+    assert_eq!(doc.node_by_id("rect1-1").unwrap().uses_count(), 1);
+
+    assert_eq!(doc.to_string_with_opt(&write_options()),
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <g id='g1'>
+        <rect id='rect1' fill='url(#lg1)'/>
+        <use xlink:href='#rect1'/>
+        <use xlink:href='#g2'/>
+    </g>
+    <g id='g2'/>
+    <g id='g1-1'>
+        <rect id='rect1-1' fill='url(#lg1)'/>
+        <use xlink:href='#rect1-1'/>
+        <use xlink:href='#g2'/>
+    </g>
+</svg>
+");
+}
+
+// Ids of detached nodes are not indexed, but must be changed anyway.
+#[test]
+fn copy_node_deep_with_ids_2() {
+    let mut doc = Document::new();
+    let mut g = doc.create_element(EId::G);
+    g.set_id("g1");
+
+    let new_g = doc.copy_node_deep_with_ids(g);
+    assert_eq!(*new_g.id(), "g1-1");
+}
+
+#[test]
+fn deep_clone_1() {
+    let text = "\
+<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <!--comment-->
+    <linearGradient id='lg1'/>
+    <linearGradient id='lg2' xlink:href='#lg1'/>
+    <rect id='rect1' fill='url(#lg2)' stroke='url(#lg1) none'/>
+    <rect id='rect1'/>
+    <text>Text</text>
+</svg>
+";
+
+    let doc = Document::from_str(text).unwrap();
+    let mut new_doc = doc.deep_clone();
+    assert_eq!(doc.to_string_with_opt(&write_options()), text);
+    assert_eq!(new_doc.to_string_with_opt(&write_options()), text);
+
+    let lg1 = new_doc.node_by_id("lg1").unwrap();
+    assert_eq!(lg1.uses_count(), 2);
+
+    // The original document is not affected.
+    let rect = new_doc.node_by_id("rect1").unwrap();
+    new_doc.remove_node(rect);
+    assert_eq!(lg1.uses_count(), 1);
+    assert_eq!(doc.node_by_id("lg1").unwrap().uses_count(), 2);
+    assert_eq!(doc.to_string_with_opt(&write_options()), text);
+}
+
+// Links to detached nodes are preserved.
+#[test]
+fn deep_clone_2() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <rect fill='url(#lg1)'/>
+</svg>").unwrap();
+
+    let mut lg = doc.node_by_id("lg1").unwrap();
+    lg.detach();
+
+    let new_doc = doc.deep_clone();
+    let rect = new_doc.svg_element().unwrap().first_child().unwrap();
+    match rect.attributes().get_value(AId::Fill) {
+        Some(AttributeValue::Paint(link, _)) => {
+            assert_eq!(*link.id(), "lg1");
+            assert!(link.parent().is_none());
+            assert_ne!(*link, lg);
+        }
+        _ => unreachable!(),
+    };
+}
+
+// Links are copied as is, without validation.
+#[test]
+fn deep_clone_3() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <rect fill='url(#lg1)'/>
+</svg>").unwrap();
+
+    // A linked element without an id can't be linked again.
+    let mut lg = doc.node_by_id("lg1").unwrap();
+    lg.set_id("");
+
+    let new_doc = doc.deep_clone();
+    let new_lg = new_doc.svg_element().unwrap().first_child().unwrap();
+    assert_eq!(new_lg.uses_count(), 1);
+}
@@ -0,0 +1,114 @@
+use svgdom::{
+    AttributeId as AId,
+    Document,
+    Node,
+};
+
+fn ids(nodes: &[Node]) -> Vec<String> {
+    nodes.iter().map(|n| n.id().clone()).collect()
+}
+
+#[test]
+fn edges_1() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <filter id='filter1'/>
+    <rect id='rect1' fill='url(#lg1)' filter='url(#filter1)'/>
+    <use id='use1' xlink:href='#rect1'/>
+</svg>").unwrap();
+
+    let graph = doc.link_graph();
+    assert_eq!(graph.edges().len(), 3);
+    assert_eq!(ids(graph.nodes()), vec!["rect1", "lg1", "filter1", "use1"]);
+
+    let rect = doc.node_by_id("rect1").unwrap();
+    let edges = graph.edges_from(&rect);
+    assert_eq!(edges.len(), 2);
+    assert!(edges.iter().any(|e| e.attribute.has_id(AId::Filter)));
+    assert!(edges.iter().any(|e| e.attribute.has_id(AId::Fill)));
+
+    let lg = doc.node_by_id("lg1").unwrap();
+    assert!(graph.edges_from(&lg).is_empty());
+}
+
+#[test]
+fn topological_order_1() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <use id='use1' xlink:href='#rect1'/>
+    <rect id='rect1' fill='url(#lg3)' stroke='url(#lg1)'/>
+    <linearGradient id='lg3' xlink:href='#lg2'/>
+    <linearGradient id='lg2' xlink:href='#lg1'/>
+    <linearGradient id='lg1'/>
+</svg>").unwrap();
+
+    let graph = doc.link_graph();
+    assert!(!graph.has_cycles());
+
+    let order = graph.topological_order().unwrap();
+    assert_eq!(ids(&order), vec!["lg1", "lg2", "lg3", "rect1", "use1"]);
+}
+
+// A cycle through different attributes.
+//
+// Dependency cycles are rejected by `set_attribute_checked`,
+// but hyperlinks can still close a cycle.
+#[test]
+fn find_cycle_1() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='rect1' width='10'/>
+    <mask id='mask1'/>
+    <filter id='filter1'/>
+    <a id='a1'/>
+</svg>").unwrap();
+
+    let mut rect = doc.node_by_id("rect1").unwrap();
+    let mut mask = doc.node_by_id("mask1").unwrap();
+    let mut filter = doc.node_by_id("filter1").unwrap();
+    let mut a = doc.node_by_id("a1").unwrap();
+
+    a.set_attribute((AId::Href, rect.clone()));
+    rect.set_attribute((AId::Mask, mask.clone()));
+    mask.set_attribute((AId::Filter, filter.clone()));
+    assert!(!doc.link_graph().has_cycles());
+
+    filter.set_attribute((AId::Href, a.clone()));
+
+    let graph = doc.link_graph();
+    assert!(graph.has_cycles());
+    assert!(graph.topological_order().is_none());
+
+    let cycle = graph.find_cycle().unwrap();
+    assert_eq!(cycle.len(), 4);
+    assert_eq!(cycle[0].source, rect);
+    assert!(cycle[0].attribute.has_id(AId::Mask));
+    assert_eq!(cycle[1].source, mask);
+    assert_eq!(cycle[2].source, filter);
+    assert_eq!(cycle[3].source, a);
+    assert_eq!(cycle[3].target, rect);
+}
+
+// A dependency cycle is not allowed.
+#[test]
+fn find_cycle_2() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='rect1' width='10'/>
+    <mask id='mask1'/>
+    <filter id='filter1'/>
+</svg>").unwrap();
+
+    let mut rect = doc.node_by_id("rect1").unwrap();
+    let mut mask = doc.node_by_id("mask1").unwrap();
+    let mut filter = doc.node_by_id("filter1").unwrap();
+
+    rect.set_attribute((AId::Mask, mask.clone()));
+    mask.set_attribute((AId::Filter, filter.clone()));
+    assert!(filter.set_attribute_checked((AId::Href, rect.clone())).is_err());
+
+    let graph = doc.link_graph();
+    assert!(graph.find_cycle().is_none());
+    assert!(graph.topological_order().is_some());
+}
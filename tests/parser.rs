@@ -7,8 +7,11 @@ use svgdom::{
     AttributeValue,
     Document,
     ElementId as EId,
+    Error,
     TagNameRef,
     NodeType,
+    ParseOptions,
+    ParserError,
     WriteOptions,
 };
 
@@ -224,6 +227,101 @@ test_resave!(parse_style_8,
 </svg>
 ");
 
+#[test]
+fn parse_keyword_1() {
+    use svgdom::{LineCap, FillRule, DisplayMode, FontWeight};
+
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path stroke-linecap='round' fill-rule='evenodd' display='none' font-weight='700'/>
+</svg>").unwrap();
+
+    let path = doc.svg_element().unwrap().first_child().unwrap();
+    let attrs = path.attributes();
+    assert_eq!(attrs.get_value(AId::StrokeLinecap).unwrap(), &AttributeValue::LineCap(LineCap::Round));
+    assert_eq!(attrs.get_value(AId::FillRule).unwrap(), &AttributeValue::FillRule(FillRule::EvenOdd));
+    assert_eq!(attrs.get_value(AId::Display).unwrap(), &AttributeValue::DisplayMode(DisplayMode::None));
+    assert_eq!(attrs.get_value(AId::FontWeight).unwrap(), &AttributeValue::FontWeight(FontWeight::W700));
+}
+
+// invalid keywords are ignored
+test_resave!(parse_keyword_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path stroke-linecap='rounded' visibility='inherit' text-anchor='Middle' clip-rule='evenodd'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path clip-rule='evenodd' visibility='inherit'/>
+</svg>
+");
+
+#[test]
+fn default_keyword_1() {
+    use svgdom::{LineCap, Visibility};
+
+    assert_eq!(AttributeValue::default_value(AId::StrokeLinecap),
+               Some(AttributeValue::LineCap(LineCap::Butt)));
+    assert_eq!(AttributeValue::default_value(AId::Visibility),
+               Some(AttributeValue::Visibility(Visibility::Visible)));
+}
+
+// CSS properties without a presentation attribute are kept in the style
+test_resave!(parse_style_9,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g style='mix-blend-mode:multiply;isolation:isolate;paint-order:stroke;\
+              vector-effect:non-scaling-stroke'/>
+</svg>
+",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g paint-order='stroke' vector-effect='non-scaling-stroke' \
+       style='isolation:isolate;mix-blend-mode:multiply'/>
+</svg>
+");
+
+test_resave!(parse_style_10,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g style='mix-blend-mode:unknown;paint-order:fill fill;vector-effect:none'/>
+</svg>
+",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g vector-effect='none'/>
+</svg>
+");
+
+// SVG 2 geometry properties are parsed only on elements that have them.
+test_resave!(parse_style_11,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect x='5' style='x:10px;y:20;width:30;height:40'/>
+    <symbol style='x:10;y:20'/>
+    <circle style='x:10;y:20'/>
+    <text style='x:10'/>
+</svg>
+",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect height='40' width='30' x='10px' y='20'/>
+    <symbol x='10' y='20'/>
+    <circle/>
+    <text/>
+</svg>
+");
+
+#[test]
+fn parse_paint_order_1() {
+    use svgdom::{PaintOrder, PaintOrderKind};
+
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path paint-order='markers stroke'/>
+</svg>").unwrap();
+
+    let path = doc.svg_element().unwrap().first_child().unwrap();
+    let order = PaintOrder {
+        order: [PaintOrderKind::Markers, PaintOrderKind::Stroke, PaintOrderKind::Fill],
+    };
+    assert_eq!(path.attributes().get_value(AId::PaintOrder).unwrap(),
+               &AttributeValue::PaintOrder(order));
+    assert_eq!(order.to_string(), "markers stroke");
+}
+
 #[test]
 fn parse_paint_1() {
     let doc = Document::from_str(
@@ -307,6 +405,179 @@ test_resave!(parse_paint_7,
 </svg>
 ");
 
+test_resave!(parse_color_alpha_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='rgba(255, 0, 0, 0.5)' stroke='#0000ff80'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='#ff0000' fill-opacity='0.5' stroke='#0000ff' stroke-opacity='0.50196078431'/>
+</svg>
+");
+
+test_resave!(parse_color_alpha_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill-opacity='0.5' fill='rgb(0 128 0 / 50%)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='#008000' fill-opacity='0.25'/>
+</svg>
+");
+
+test_resave!(parse_color_alpha_3,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient>
+        <stop stop-color='hsla(120, 100%, 25%, 0.5)'/>
+        <stop stop-color='hsl(240deg 100% 50%)'/>
+        <stop stop-color='transparent'/>
+    </linearGradient>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient>
+        <stop stop-color='#008000' stop-opacity='0.5'/>
+        <stop stop-color='#0000ff'/>
+        <stop stop-color='#000000' stop-opacity='0'/>
+    </linearGradient>
+</svg>
+");
+
+// Alpha of the overridden color must be ignored.
+test_resave!(parse_color_alpha_4,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='rgba(255, 0, 0, 0.5)' style='fill:green'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='#008000'/>
+</svg>
+");
+
+test_resave!(parse_color_alpha_5,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='url(#lg1) #f008'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='#ff0000' fill-opacity='0.53333333333'/>
+</svg>
+");
+
+test_resave!(parse_color_alpha_6,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='hwb(0 0% 0%)' stroke='rgba(1, 2, 3)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='#ff0000' stroke='#010203'/>
+</svg>
+");
+
+// Descendants that override the color must not inherit the alpha.
+test_resave!(parse_color_alpha_7,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill='rgba(255, 0, 0, 0.5)'>
+        <rect fill='blue'/>
+        <rect/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill='#ff0000' fill-opacity='0.5'>
+        <rect fill='#0000ff' fill-opacity='1'/>
+        <rect/>
+    </g>
+</svg>
+");
+
+test_resave!(parse_color_alpha_8,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill-opacity='0.8'>
+        <g fill='rgba(255, 0, 0, 0.5)'>
+            <rect fill='blue'/>
+            <rect fill-opacity='0.5'/>
+            <g fill-opacity='0.4'>
+                <rect fill='green'/>
+            </g>
+        </g>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill-opacity='0.8'>
+        <g fill='#ff0000' fill-opacity='0.4'>
+            <rect fill='#0000ff' fill-opacity='0.8'/>
+            <rect fill-opacity='0.25'/>
+            <g fill-opacity='0.2'>
+                <rect fill='#008000' fill-opacity='0.4'/>
+            </g>
+        </g>
+    </g>
+</svg>
+");
+
+#[test]
+fn skip_invalid_attributes_1() {
+    let text = "\
+<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect x='10' r='5' cx='20' fill='red' style='stroke:green'/>
+    <circle x='10' r='5'/>
+    <symbol x='10' refX='5' cx='20'/>
+</svg>";
+
+    let opt = ParseOptions {
+        skip_invalid_attributes: true,
+        ..ParseOptions::default()
+    };
+    let doc = Document::from_str_with_opt(text, &opt).unwrap();
+
+    assert_eq!(TStr(doc.to_string_with_opt(&write_options()).as_str()), TStr(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='#ff0000' stroke='#008000' x='10'/>
+    <circle r='5'/>
+    <symbol refX='5' x='10'/>
+</svg>
+"));
+
+    // Disabled by default.
+    let doc = Document::from_str(text).unwrap();
+    let rect = doc.root().descendants().find(|n| n.has_tag_name(EId::Rect)).unwrap();
+    assert_eq!(rect.has_attribute(AId::R), true);
+}
+
+#[test]
+fn duplicated_ids_1() {
+    let text = "\
+<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <radialGradient id='lg1'/>
+    <rect fill='url(#lg1)'/>
+</svg>";
+
+    // Links are resolved to the first element.
+    let doc = Document::from_str(text).unwrap();
+    let rect = doc.root().descendants().find(|n| n.has_tag_name(EId::Rect)).unwrap();
+    match rect.attributes().get_value(AId::Fill) {
+        Some(AttributeValue::Paint(link, _)) => {
+            assert!(link.has_tag_name(EId::LinearGradient));
+        }
+        _ => unreachable!(),
+    }
+
+    let opt = ParseOptions {
+        fail_on_duplicated_ids: true,
+        ..ParseOptions::default()
+    };
+    match Document::from_str_with_opt(text, &opt) {
+        Err(ParserError::DomError(Error::DuplicatedId(ref id))) => assert_eq!(id, "lg1"),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn allows_attribute_1() {
+    assert_eq!(EId::Rect.allows_attribute(AId::Rx), true);
+    assert_eq!(EId::Rect.allows_attribute(AId::R), false);
+    assert_eq!(EId::Rect.allows_attribute(AId::Fill), true);
+    assert_eq!(EId::Stop.allows_attribute(AId::Offset), true);
+    assert_eq!(EId::Stop.allows_attribute(AId::Transform), false);
+    assert_eq!(EId::FeFuncA.allows_attribute(AId::Fill), false);
+    assert_eq!(EId::Use.allows_attribute(AId::Href), true);
+}
+
 test_resave!(parse_invalid_path,
 "<svg xmlns='http://www.w3.org/2000/svg'>
     <path d='M 10 20 L 30 40 L 50'/>
@@ -347,6 +618,41 @@ fn parse_iri_2() {
                &AttributeValue::String("#r1".to_string()));
 }
 
+// SVG 2 `href` takes precedence over `xlink:href`
+test_resave!(parse_iri_3,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='r1'/>
+    <rect id='r2'/>
+    <use href='#r2' xlink:href='#r1'/>
+    <use xlink:href='#r1' href='#r2'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='r1'/>
+    <rect id='r2'/>
+    <use xlink:href='#r2'/>
+    <use xlink:href='#r2'/>
+</svg>
+");
+
+test_resave!(parse_svg2_elements_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <solidcolor id='sc1' solid-color='red' solid-opacity='0.5'/>
+    <hatch id='h1' pitch='5' hatchUnits='userSpaceOnUse'>
+        <hatchpath d='M 0 0 L 10 10'/>
+    </hatch>
+    <radialGradient id='rg1' fr='10%'/>
+    <rect fill='url(#sc1)' stroke='url(#h1)' transform-origin='center'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <solidcolor id='sc1' solid-color='#ff0000' solid-opacity='0.5'/>
+    <hatch id='h1' hatchUnits='userSpaceOnUse' pitch='5'>
+        <hatchpath d='M 0 0 L 10 10'/>
+    </hatch>
+    <radialGradient id='rg1' fr='10%'/>
+    <rect fill='url(#sc1)' stroke='url(#h1)' transform-origin='center'/>
+</svg>
+");
+
 #[test]
 fn parse_func_iri_1() {
     let doc = Document::from_str(
@@ -443,6 +749,179 @@ test_resave!(crosslink_3,
 "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
     <linearGradient id='lg1' xlink:href='#lg2'/>
     <linearGradient id='lg2' xlink:href='#lg3'/>
-    <linearGradient id='lg3' xlink:href='#lg1'/>
+    <linearGradient id='lg3'/>
+</svg>
+");
+
+// A cycle through different attributes and children.
+test_resave!(crosslink_4,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='rect1' mask='url(#mask1)'/>
+    <mask id='mask1'>
+        <rect filter='url(#filter1)'/>
+    </mask>
+    <filter id='filter1'>
+        <feImage xlink:href='#rect1'/>
+    </filter>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='rect1' mask='url(#mask1)'/>
+    <mask id='mask1'>
+        <rect filter='url(#filter1)'/>
+    </mask>
+    <filter id='filter1'>
+        <feImage/>
+    </filter>
+</svg>
+");
+
+// A `use` element that references its own ancestor.
+test_resave!(crosslink_5,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <g id='g1'>
+        <g>
+            <use xlink:href='#g1'/>
+        </g>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g id='g1'>
+        <g>
+            <use/>
+        </g>
+    </g>
+</svg>
+");
+
+// Hyperlinks can reference ancestors.
+test_resave!(crosslink_6,
+"<svg id='svg1' xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <a xlink:href='#svg1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink' id='svg1'>
+    <a xlink:href='#svg1'/>
 </svg>
 ");
+
+// Checking links for cycles must not depend on the size of the referenced subtree.
+#[test]
+fn crosslink_7() {
+    let mut text = String::from("<svg xmlns='http://www.w3.org/2000/svg' \
+                                      xmlns:xlink='http://www.w3.org/1999/xlink'>");
+    text.push_str("<g id='big'>");
+    for _ in 0..4000 {
+        text.push_str("<rect/>");
+    }
+    text.push_str("</g>");
+    for _ in 0..4000 {
+        text.push_str("<use xlink:href='#big'/>");
+    }
+    text.push_str("</svg>");
+
+    let doc = Document::from_str(&text).unwrap();
+    let big = doc.node_by_id("big").unwrap();
+    assert_eq!(big.linked_nodes().len(), 4000);
+}
+
+test_resave!(external_links_1,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <use xlink:href='sprite.svg#icon'/>
+    <rect fill='url(sprite.svg#lg1) red' mask=\"url('masks.svg#mask1')\"/>
+    <a xlink:href='page.svg#top'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <use xlink:href='sprite.svg#icon'/>
+    <rect fill='url(sprite.svg#lg1) #ff0000' mask='url(masks.svg#mask1)'/>
+    <a xlink:href='page.svg#top'/>
+</svg>
+");
+
+#[test]
+fn external_links_2() {
+    let sprite = "\
+<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'>
+        <stop offset='0'/>
+    </linearGradient>
+    <symbol id='icon'>
+        <rect width='10' height='10'/>
+    </symbol>
+</svg>";
+
+    let opt = ParseOptions {
+        external_resolver: Some(Box::new(move |path| {
+            if path == "sprite.svg" {
+                Document::from_str(sprite).ok()
+            } else {
+                None
+            }
+        })),
+        ..ParseOptions::default()
+    };
+
+    let doc = Document::from_str_with_opt(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='icon'/>
+    <use xlink:href='sprite.svg#icon'/>
+    <use xlink:href='sprite.svg#icon'/>
+    <rect fill='url(sprite.svg#lg1)'/>
+    <rect fill='url(other.svg#lg1)'/>
+</svg>", &opt).unwrap();
+
+    let rect = doc.root().descendants().filter(|n| n.has_tag_name(EId::Rect)).last().unwrap();
+    assert_eq!(rect.attributes().get_value(AId::Fill).unwrap().is_external_func_link(), true);
+
+    assert_eq!(doc.to_string_with_opt(&write_options()),
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <symbol id='icon-1'>
+            <rect height='10' width='10'/>
+        </symbol>
+        <linearGradient id='lg1'>
+            <stop offset='0'/>
+        </linearGradient>
+    </defs>
+    <rect id='icon'/>
+    <use xlink:href='#icon-1'/>
+    <use xlink:href='#icon-1'/>
+    <rect fill='url(#lg1)'/>
+    <rect fill='url(other.svg#lg1)'/>
+</svg>
+");
+}
+
+// Elements shared by different references are imported only once.
+#[test]
+fn external_links_3() {
+    let sprite = "\
+<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <symbol id='icon'>
+        <rect fill='url(#lg1)'/>
+    </symbol>
+</svg>";
+
+    let opt = ParseOptions {
+        external_resolver: Some(Box::new(move |_| Document::from_str(sprite).ok())),
+        ..ParseOptions::default()
+    };
+
+    let doc = Document::from_str_with_opt(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <use xlink:href='sprite.svg#icon'/>
+    <rect fill='url(sprite.svg#lg1)'/>
+</svg>", &opt).unwrap();
+
+    assert_eq!(doc.to_string_with_opt(&write_options()),
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <symbol id='icon'>
+            <rect fill='url(#lg1)'/>
+        </symbol>
+        <linearGradient id='lg1'/>
+    </defs>
+    <use xlink:href='#icon'/>
+    <rect fill='url(#lg1)'/>
+</svg>
+");
+}
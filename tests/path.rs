@@ -0,0 +1,128 @@
+use svgdom::{
+    Document,
+    FuzzyEq,
+    NormalizeOptions,
+    Path,
+    PathNormalize,
+    PathSegment,
+};
+
+macro_rules! test {
+    ($name:ident, $opt:expr, $in_text:expr, $out_text:expr) => (
+        #[test]
+        fn $name() {
+            let mut path: Path = $in_text.parse().unwrap();
+            path.normalize(&$opt);
+            assert_eq!(path.to_string(), $out_text);
+        }
+    )
+}
+
+fn no_conv() -> NormalizeOptions {
+    NormalizeOptions {
+        expand_shorthands: false,
+        quads_to_cubics: false,
+        arcs_to_cubics: false,
+        .. NormalizeOptions::default()
+    }
+}
+
+test!(absolute_1, no_conv(),
+    "m 10 20 l 10 10 h 5 v 5 c 1 1 2 2 3 3 s 4 4 5 5 q 1 1 2 2 t 3 3 a 5 5 0 0 1 10 0 z m 1 1",
+    "M 10 20 L 20 30 H 25 V 35 C 26 36 27 37 28 38 S 32 42 33 43 Q 34 44 35 45 T 38 48 \
+     A 5 5 0 0 1 48 48 Z M 11 21");
+
+test!(shorthands_1, NormalizeOptions { expand_shorthands: true, .. no_conv() },
+    "M 10 20 H 30 V 40 S 50 60 70 80 S 90 100 110 120",
+    "M 10 20 L 30 20 L 30 40 C 30 40 50 60 70 80 C 90 100 90 100 110 120");
+
+test!(shorthands_2, NormalizeOptions { expand_shorthands: true, .. no_conv() },
+    "M 0 0 Q 10 10 20 0 T 40 0 T 60 0",
+    "M 0 0 Q 10 10 20 0 Q 30 -10 40 0 Q 50 10 60 0");
+
+test!(shorthands_3, NormalizeOptions { expand_shorthands: true, .. no_conv() },
+    "M 0 0 L 10 10 S 20 20 30 0 T 40 0",
+    "M 0 0 L 10 10 C 10 10 20 20 30 0 Q 30 0 40 0");
+
+test!(quads_1, NormalizeOptions { quads_to_cubics: true, .. no_conv() },
+    "M 0 0 Q 30 30 60 0 T 120 0",
+    "M 0 0 C 20 20 40 20 60 0 C 80 -20 100 -20 120 0");
+
+// `S` after a converted quadratic curve must not reflect its control point.
+test!(quads_2, NormalizeOptions { quads_to_cubics: true, .. no_conv() },
+    "M 0 0 Q 30 30 60 0 S 70 10 80 0",
+    "M 0 0 C 20 20 40 20 60 0 C 60 0 70 10 80 0");
+
+test!(smooth_preserved_1, NormalizeOptions { quads_to_cubics: true, .. no_conv() },
+    "M 0 0 C 10 10 20 10 30 0 S 50 -10 60 0",
+    "M 0 0 C 10 10 20 10 30 0 S 50 -10 60 0");
+
+test!(arcs_1, NormalizeOptions::default(),
+    "M 10 20 A 0 5 0 0 1 30 20 A 5 5 0 0 1 30 20",
+    "M 10 20 L 30 20");
+
+#[test]
+fn arcs_2() {
+    let opt = NormalizeOptions { arc_tolerance: 0.0, .. NormalizeOptions::default() };
+    let mut path: Path = "M 10 0 A 10 10 0 0 1 0 10".parse().unwrap();
+    path.normalize(&opt);
+
+    assert_eq!(path.len(), 2);
+    let k = 10.0 * 4.0 / 3.0 * (std::f64::consts::PI / 8.0).tan();
+    match path[1] {
+        PathSegment::CurveTo { abs, x1, y1, x2, y2, x, y } => {
+            assert!(abs);
+            assert!(x1.fuzzy_eq(&10.0) && y1.fuzzy_eq(&k));
+            assert!(x2.fuzzy_eq(&k) && y2.fuzzy_eq(&10.0));
+            assert!(x.fuzzy_eq(&0.0) && y.fuzzy_eq(&10.0));
+        }
+        _ => panic!("invalid segment"),
+    }
+}
+
+#[test]
+fn arcs_3() {
+    // A full circle is split into at least 4 segments
+    // and into more on a smaller tolerance.
+    let text = "M 100 0 A 100 100 0 1 1 -100 0 A 100 100 0 1 1 100 0";
+
+    let mut path: Path = text.parse().unwrap();
+    path.normalize(&NormalizeOptions { arc_tolerance: 0.0, .. NormalizeOptions::default() });
+    assert_eq!(path.len(), 5);
+
+    let mut path: Path = text.parse().unwrap();
+    path.normalize(&NormalizeOptions { arc_tolerance: 0.01, .. NormalizeOptions::default() });
+    assert!(path.len() > 5);
+
+    for seg in path.iter().skip(1) {
+        match *seg {
+            PathSegment::CurveTo { x, y, .. } => {
+                assert!((x * x + y * y).sqrt().fuzzy_eq(&100.0));
+            }
+            _ => panic!("invalid segment"),
+        }
+    }
+}
+
+#[test]
+fn document_1() {
+    let mut doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='m 10 20 h 10'/>
+    <rect width='10' height='10'/>
+    <g>
+        <path d='M 10 20 V 30'/>
+    </g>
+</svg>").unwrap();
+
+    assert_eq!(doc.normalize_paths(&NormalizeOptions::default()), 2);
+    assert_eq!(doc.to_string(),
+"<svg xmlns=\"http://www.w3.org/2000/svg\">
+    <path d=\"M 10 20 L 20 20\"/>
+    <rect height=\"10\" width=\"10\"/>
+    <g>
+        <path d=\"M 10 20 L 10 30\"/>
+    </g>
+</svg>
+");
+}
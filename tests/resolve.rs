@@ -0,0 +1,399 @@
+#[macro_use] extern crate pretty_assertions;
+
+use svgdom::{
+    Document,
+    WriteOptions,
+};
+
+macro_rules! test {
+    ($name:ident, $method:ident, $in_text:expr, $out_text:expr) => (
+        #[test]
+        fn $name() {
+            let mut doc = Document::from_str($in_text).unwrap();
+            doc.$method();
+
+            let mut opt = WriteOptions::default();
+            opt.use_single_quote = true;
+
+            assert_eq!(doc.to_string_with_opt(&opt), $out_text);
+        }
+    )
+}
+
+test!(use_1, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='rect1' width='10' height='20'/>
+    <use id='use1' x='5' y='10' fill='red' transform='scale(2)' xlink:href='#rect1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='rect1' height='20' width='10'/>
+    <g id='use1' fill='#ff0000' transform='matrix(2 0 0 2 10 20)'>
+        <rect height='20' width='10'/>
+    </g>
+</svg>
+");
+
+test!(use_2, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <symbol id='sym1' viewBox='0 0 10 10'>
+        <rect width='10' height='10'/>
+    </symbol>
+    <use width='20' height='30' xlink:href='#sym1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <defs>
+        <clipPath id='clipPath1'>
+            <rect height='15' width='10' x='0' y='-2.5'/>
+        </clipPath>
+    </defs>
+    <symbol id='sym1' viewBox='0 0 10 10'>
+        <rect height='10' width='10'/>
+    </symbol>
+    <g>
+        <g clip-path='url(#clipPath1)' transform='matrix(2 0 0 2 0 5)'>
+            <rect height='10' width='10'/>
+        </g>
+    </g>
+</svg>
+");
+
+test!(use_3, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <svg id='svg1' width='10' height='10'/>
+    </defs>
+    <use width='20' xlink:href='#svg1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <defs>
+        <svg id='svg1' height='10' width='10'/>
+    </defs>
+    <g>
+        <g/>
+    </g>
+</svg>
+");
+
+// Nested `use` elements.
+test!(use_4, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <rect id='rect1' width='10' height='20'/>
+        <g id='g1'>
+            <use xlink:href='#rect1'/>
+        </g>
+    </defs>
+    <use xlink:href='#g1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <defs>
+        <rect id='rect1' height='20' width='10'/>
+        <g id='g1'>
+            <g>
+                <rect height='20' width='10'/>
+            </g>
+        </g>
+    </defs>
+    <g>
+        <g>
+            <g>
+                <rect height='20' width='10'/>
+            </g>
+        </g>
+    </g>
+</svg>
+");
+
+// Links inside the copied content are preserved.
+test!(use_5, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'/>
+    <rect id='rect1' fill='url(#lg1)'/>
+    <use xlink:href='#rect1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'/>
+    <rect id='rect1' fill='url(#lg1)'/>
+    <g>
+        <rect fill='url(#lg1)'/>
+    </g>
+</svg>
+");
+
+test!(use_6, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <symbol id='sym1' viewBox='5 0 10 10' preserveAspectRatio='xMaxYMax slice'
+            overflow='visible' fill='red'>
+        <rect width='10' height='10'/>
+    </symbol>
+    <symbol id='sym2' viewBox='0 0 10 10' preserveAspectRatio='none' overflow='visible'>
+        <rect width='10' height='10'/>
+    </symbol>
+    <use width='20' height='30' xlink:href='#sym1'/>
+    <use width='20' height='30' xlink:href='#sym2'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <symbol id='sym1' fill='#ff0000' overflow='visible' preserveAspectRatio='xMaxYMax slice' viewBox='5 0 10 10'>
+        <rect height='10' width='10'/>
+    </symbol>
+    <symbol id='sym2' overflow='visible' preserveAspectRatio='none' viewBox='0 0 10 10'>
+        <rect height='10' width='10'/>
+    </symbol>
+    <g>
+        <g fill='#ff0000' transform='matrix(3 0 0 3 -25 0)'>
+            <rect height='10' width='10'/>
+        </g>
+    </g>
+    <g>
+        <g transform='matrix(2 0 0 3 0 0)'>
+            <rect height='10' width='10'/>
+        </g>
+    </g>
+</svg>
+");
+
+// A viewport with a zero size is not rendered.
+test!(use_7, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <symbol id='sym1'>
+        <rect width='10' height='10'/>
+    </symbol>
+    <use width='0' xlink:href='#sym1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <symbol id='sym1'>
+        <rect height='10' width='10'/>
+    </symbol>
+    <g>
+        <g/>
+    </g>
+</svg>
+");
+
+// The clipping rectangle covers the viewport position.
+test!(use_8, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <svg id='svg1' x='10' y='20' width='30' height='40'>
+        <rect width='100' height='100'/>
+    </svg>
+    <svg id='svg2' x='10' y='20' width='30' height='40' viewBox='5 0 15 20'>
+        <rect width='100' height='100'/>
+    </svg>
+    <use xlink:href='#svg1'/>
+    <use xlink:href='#svg2'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <defs>
+        <clipPath id='clipPath1'>
+            <rect height='40' width='30' x='0' y='0'/>
+        </clipPath>
+        <clipPath id='clipPath2'>
+            <rect height='20' width='15' x='5' y='0'/>
+        </clipPath>
+    </defs>
+    <svg id='svg1' height='40' width='30' x='10' y='20'>
+        <rect height='100' width='100'/>
+    </svg>
+    <svg id='svg2' height='40' viewBox='5 0 15 20' width='30' x='10' y='20'>
+        <rect height='100' width='100'/>
+    </svg>
+    <g>
+        <g clip-path='url(#clipPath1)' transform='matrix(1 0 0 1 10 20)'>
+            <rect height='100' width='100'/>
+        </g>
+    </g>
+    <g>
+        <g clip-path='url(#clipPath2)' transform='matrix(2 0 0 2 0 20)'>
+            <rect height='100' width='100'/>
+        </g>
+    </g>
+</svg>
+");
+
+// Each level multiplies the number of nodes by 10.
+#[test]
+fn use_limit_1() {
+    let mut text = String::from("<svg xmlns='http://www.w3.org/2000/svg' \
+                                 xmlns:xlink='http://www.w3.org/1999/xlink'>\n");
+    text.push_str("<g id='g0'><rect/></g>\n");
+    for i in 1..7 {
+        text.push_str(&format!("<g id='g{}'>", i));
+        for _ in 0..10 {
+            text.push_str(&format!("<use xlink:href='#g{}'/>", i - 1));
+        }
+        text.push_str("</g>\n");
+    }
+    text.push_str("</svg>");
+
+    let mut doc = Document::from_str(&text).unwrap();
+    doc.resolve_use();
+
+    assert_eq!(doc.root().descendants().count() < 200_000, true);
+}
+
+// A `use` element inside the referenced element.
+//
+// Recursive links are removed by the parser.
+test!(recursive_1, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <g id='g1'>
+        <use xlink:href='#g1'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g id='g1'>
+        <use/>
+    </g>
+</svg>
+");
+
+// `use` elements referencing each other.
+test!(recursive_2, resolve_use,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <defs>
+        <g id='g1'>
+            <use xlink:href='#g2'/>
+        </g>
+        <g id='g2'>
+            <use xlink:href='#g1'/>
+        </g>
+    </defs>
+    <use xlink:href='#g1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <defs>
+        <g id='g1'>
+            <g>
+                <g>
+                    <use/>
+                </g>
+            </g>
+        </g>
+        <g id='g2'>
+            <use/>
+        </g>
+    </defs>
+    <g>
+        <g>
+            <g>
+                <g>
+                    <use/>
+                </g>
+            </g>
+        </g>
+    </g>
+</svg>
+");
+
+#[test]
+fn resolve_use_node_1() {
+    let mut doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='rect1'/>
+    <use id='use1' xlink:href='#rect1'/>
+    <use id='use2' xlink:href='#rect1'/>
+</svg>").unwrap();
+
+    let rect = doc.node_by_id("rect1").unwrap();
+    let use1 = doc.node_by_id("use1").unwrap();
+
+    assert_eq!(doc.resolve_use_node(rect), false);
+    assert_eq!(doc.resolve_use_node(use1), true);
+
+    let opt = WriteOptions {
+        use_single_quote: true,
+        ..WriteOptions::default()
+    };
+
+    assert_eq!(doc.to_string_with_opt(&opt),
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='rect1'/>
+    <g id='use1'>
+        <rect/>
+    </g>
+    <use id='use2' xlink:href='#rect1'/>
+</svg>
+");
+}
+
+test!(href_1, resolve_href_inheritance,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1' x1='5' gradientUnits='userSpaceOnUse'>
+        <stop offset='0'/>
+        <stop offset='1'/>
+    </linearGradient>
+    <linearGradient id='lg2' y1='10' spreadMethod='reflect' xlink:href='#lg1'/>
+    <linearGradient id='lg3' x1='20' xlink:href='#lg2'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1' gradientUnits='userSpaceOnUse' x1='5'>
+        <stop offset='0'/>
+        <stop offset='1'/>
+    </linearGradient>
+    <linearGradient id='lg2' gradientUnits='userSpaceOnUse' spreadMethod='reflect' x1='5' y1='10'>
+        <stop offset='0'/>
+        <stop offset='1'/>
+    </linearGradient>
+    <linearGradient id='lg3' gradientUnits='userSpaceOnUse' spreadMethod='reflect' x1='20' y1='10'>
+        <stop offset='0'/>
+        <stop offset='1'/>
+    </linearGradient>
+</svg>
+");
+
+// Geometry attributes are inherited only from the same gradient type.
+test!(href_2, resolve_href_inheritance,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1' x1='5' gradientUnits='userSpaceOnUse'/>
+    <radialGradient id='rg1' r='10' xlink:href='#lg1'>
+        <stop offset='0'/>
+    </radialGradient>
+    <linearGradient id='lg2' xlink:href='#rg1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1' gradientUnits='userSpaceOnUse' x1='5'/>
+    <radialGradient id='rg1' gradientUnits='userSpaceOnUse' r='10'>
+        <stop offset='0'/>
+    </radialGradient>
+    <linearGradient id='lg2' gradientUnits='userSpaceOnUse' x1='5'>
+        <stop offset='0'/>
+    </linearGradient>
+</svg>
+");
+
+test!(href_3, resolve_href_inheritance,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <pattern id='patt1' width='10' height='10' patternUnits='userSpaceOnUse'>
+        <rect width='5' height='5'/>
+    </pattern>
+    <pattern id='patt2' width='20' patternTransform='scale(2)' xlink:href='#patt1'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <pattern id='patt1' height='10' patternUnits='userSpaceOnUse' width='10'>
+        <rect height='5' width='5'/>
+    </pattern>
+    <pattern id='patt2' height='10' patternTransform='matrix(2 0 0 2 0 0)' patternUnits='userSpaceOnUse' width='20'>
+        <rect height='5' width='5'/>
+    </pattern>
+</svg>
+");
+
+// Own stops are preserved.
+test!(href_4, resolve_href_inheritance,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <linearGradient id='lg1'>
+        <stop offset='0'/>
+    </linearGradient>
+    <linearGradient id='lg2' xlink:href='#lg1'>
+        <stop offset='1'/>
+    </linearGradient>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg1'>
+        <stop offset='0'/>
+    </linearGradient>
+    <linearGradient id='lg2'>
+        <stop offset='1'/>
+    </linearGradient>
+</svg>
+");
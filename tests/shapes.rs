@@ -0,0 +1,118 @@
+use svgdom::{
+    Document,
+    WriteOptions,
+};
+
+macro_rules! test {
+    ($name:ident, $in_text:expr, $out_text:expr) => (
+        #[test]
+        fn $name() {
+            let mut doc = Document::from_str($in_text).unwrap();
+            doc.convert_shapes_to_paths();
+
+            let mut opt = WriteOptions::default();
+            opt.use_single_quote = true;
+
+            assert_eq!(doc.to_string_with_opt(&opt), $out_text);
+        }
+    )
+}
+
+test!(rect_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='r' x='10' y='20' width='30' height='40' fill='red'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path id='r' d='M 10 20 H 40 V 60 H 10 Z' fill='#ff0000'/>
+</svg>
+");
+
+test!(rect_rounded_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect width='30' height='40' rx='5'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 5 0 H 25 A 5 5 0 0 1 30 5 V 35 A 5 5 0 0 1 25 40 H 5 A 5 5 0 0 1 0 35 V 5 \
+A 5 5 0 0 1 5 0 Z'/>
+</svg>
+");
+
+test!(rect_rounded_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect width='20' height='40' rx='-5' ry='50'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 10 0 H 10 A 10 20 0 0 1 20 20 V 20 A 10 20 0 0 1 10 40 H 10 A 10 20 0 0 1 0 20 V 20 \
+A 10 20 0 0 1 10 0 Z'/>
+</svg>
+");
+
+test!(rect_invalid_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect width='0' height='40'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect height='40' width='0'/>
+</svg>
+");
+
+test!(circle_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <circle cx='50' cy='50' r='10' transform='scale(2)'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 60 50 A 10 10 0 0 1 50 60 A 10 10 0 0 1 40 50 A 10 10 0 0 1 50 40 \
+A 10 10 0 0 1 60 50 Z' transform='matrix(2 0 0 2 0 0)'/>
+</svg>
+");
+
+test!(ellipse_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <ellipse rx='20' ry='10'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 20 0 A 20 10 0 0 1 0 10 A 20 10 0 0 1 -20 0 A 20 10 0 0 1 0 -10 \
+A 20 10 0 0 1 20 0 Z'/>
+</svg>
+");
+
+test!(line_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <line x1='10' y1='20' x2='1in' y2='40'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 10 20 L 96 40'/>
+</svg>
+");
+
+test!(polyline_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <polyline points='10 20 30 40 50 60'/>
+    <polygon points='10 20 30 40 50 60'/>
+    <polygon points='10 20'/>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <path d='M 10 20 L 30 40 L 50 60'/>
+    <path d='M 10 20 L 30 40 L 50 60 Z'/>
+    <polygon points='10 20'/>
+</svg>
+");
+
+test!(links_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg'/>
+    <marker id='m'/>
+    <g marker-start='url(#m)'>
+        <rect width='10' height='10' fill='url(#lg)'/>
+        <line x2='10' stroke='url(#lg)'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg'/>
+    <marker id='m'/>
+    <g marker-start='url(#m)'>
+        <path d='M 0 0 H 10 V 10 H 0 Z' fill='url(#lg)' marker-start='none'/>
+        <path d='M 0 0 L 10 0' stroke='url(#lg)'/>
+    </g>
+</svg>
+");
@@ -0,0 +1,185 @@
+#[macro_use] extern crate pretty_assertions;
+
+use svgdom::{
+    Document,
+    WriteOptions,
+};
+
+macro_rules! test {
+    ($name:ident, $in_text:expr, $out_text:expr) => (
+        #[test]
+        fn $name() {
+            let mut doc = Document::from_str($in_text).unwrap();
+            doc.ungroup_groups();
+
+            let mut opt = WriteOptions::default();
+            opt.use_single_quote = true;
+
+            assert_eq!(doc.to_string_with_opt(&opt), $out_text);
+        }
+    )
+}
+
+test!(empty_attributes_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g>
+        <rect id='r1'/>
+        <g>
+            <rect id='r2'/>
+            <rect id='r3'/>
+        </g>
+        <g/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='r1'/>
+    <rect id='r2'/>
+    <rect id='r3'/>
+</svg>
+");
+
+test!(merge_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g transform='translate(10 20)' fill='red' stroke='blue' display='inline'>
+        <rect stroke='green' stroke-width='inherit' transform='scale(2)'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect display='inline' fill='#ff0000' stroke='#008000' stroke-width='inherit' \
+transform='matrix(2 0 0 2 10 20)'/>
+</svg>
+");
+
+test!(merge_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g stroke-width='inherit' fill='red'>
+        <rect fill='inherit'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect fill='#ff0000' stroke-width='inherit'/>
+</svg>
+");
+
+test!(merge_links_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg'/>
+    <g fill='url(#lg)'>
+        <rect/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <linearGradient id='lg'/>
+    <rect fill='url(#lg)'/>
+</svg>
+");
+
+test!(conflict_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g display='none'>
+        <rect display='inline'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g display='none'>
+        <rect display='inline'/>
+    </g>
+</svg>
+");
+
+test!(conflict_2,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill='red'>
+        <rect/>
+        <rect/>
+    </g>
+    <g fill='red'>
+        <linearGradient/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <g fill='#ff0000'>
+        <rect/>
+        <rect/>
+    </g>
+    <g fill='#ff0000'>
+        <linearGradient/>
+    </g>
+</svg>
+");
+
+test!(clip_path_1,
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <clipPath id='clip'/>
+    <mask id='mask'/>
+    <filter id='filter'/>
+    <g clip-path='url(#clip)'>
+        <rect transform='scale(2)'/>
+    </g>
+    <g mask='url(#mask)'>
+        <rect/>
+    </g>
+    <g filter='url(#filter)'>
+        <rect/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <clipPath id='clip'/>
+    <mask id='mask'/>
+    <filter id='filter'/>
+    <g clip-path='url(#clip)'>
+        <rect transform='matrix(2 0 0 2 0 0)'/>
+    </g>
+    <g mask='url(#mask)'>
+        <rect/>
+    </g>
+    <g filter='url(#filter)'>
+        <rect/>
+    </g>
+</svg>
+");
+
+test!(used_1,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <g id='g1'>
+        <rect/>
+    </g>
+    <use xlink:href='#g1'/>
+    <switch>
+        <g>
+            <rect/>
+        </g>
+    </switch>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <g id='g1'>
+        <rect/>
+    </g>
+    <use xlink:href='#g1'/>
+    <switch>
+        <g>
+            <rect/>
+        </g>
+    </switch>
+</svg>
+");
+
+// Moving the fill into the rect would create a crosslink.
+test!(crosslink_1,
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <pattern id='patt1'>
+        <use xlink:href='#rect1'/>
+    </pattern>
+    <g fill='url(#patt1)' opacity='0.5'>
+        <rect id='rect1'/>
+    </g>
+</svg>",
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <pattern id='patt1'>
+        <use xlink:href='#rect1'/>
+    </pattern>
+    <g fill='url(#patt1)' opacity='0.5'>
+        <rect id='rect1'/>
+    </g>
+</svg>
+");
@@ -93,18 +93,19 @@ fn child_nodes_1() {
 fn links_1() {
     let mut doc = Document::new();
     let mut svg_n = doc.create_element(EId::Svg);
-    let mut use_n = doc.create_element(EId::Use);
+    let mut a_n = doc.create_element(EId::A);
 
     svg_n.set_id("svg1");
 
     doc.root().append(svg_n.clone());
-    svg_n.append(use_n.clone());
+    svg_n.append(a_n.clone());
 
-    use_n.set_attribute((AId::Href, svg_n));
+    // Only hyperlinks can reference an ancestor.
+    a_n.set_attribute((AId::Href, svg_n));
 
     assert_eq!(doc.to_string(),
 "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" id=\"svg1\">
-    <use xlink:href=\"#svg1\"/>
+    <a xlink:href=\"#svg1\"/>
 </svg>
 ");
 }
@@ -455,3 +456,25 @@ test_resave!(non_svg_1,
     <rect/>
 </svg>
 ");
+
+#[test]
+fn plain_href_1() {
+    let doc = Document::from_str(
+"<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+    <rect id='rect1'/>
+    <use xlink:href='#rect1'/>
+</svg>
+").unwrap();
+
+    let opt = WriteOptions {
+        use_plain_href: true,
+        use_single_quote: true,
+        ..WriteOptions::default()
+    };
+    assert_eq!(doc.to_string_with_opt(&opt),
+"<svg xmlns='http://www.w3.org/2000/svg'>
+    <rect id='rect1'/>
+    <use href='#rect1'/>
+</svg>
+");
+}